@@ -0,0 +1,67 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autali::moves::NfaWordAlignmentMove;
+
+
+/// Symbol used in the alignment table for the missing side of log and model moves.
+pub const NFA_WORD_ALIGNMENT_NO_MOVE_SYMBOL : &str = ">>";
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NfaWordAlignment {
+    pub moves : Vec<NfaWordAlignmentMove>,
+    pub cost : u32,
+    // cost of the worst alignment i.e. only log moves followed by the cheapest run of the NFA with only model moves
+    pub worst_cost : u32
+}
+
+impl NfaWordAlignment {
+    pub fn new(moves: Vec<NfaWordAlignmentMove>, cost: u32, worst_cost: u32) -> Self {
+        Self { moves, cost, worst_cost }
+    }
+
+    /// Fitness as usually defined in process mining: 1 - cost / worst cost.
+    pub fn get_fitness(&self) -> f64 {
+        if self.worst_cost == 0 {
+            1.0
+        } else {
+            1.0 - (self.cost as f64) / (self.worst_cost as f64)
+        }
+    }
+
+    /// Prints the alignment as a table with the word on the first row and the run of the NFA on the second.
+    pub fn to_table_string<Printer : AbstractLanguagePrinter<usize>>(&self, printer : &Printer) -> String {
+        let mut log_row = vec!["log".to_string()];
+        let mut model_row = vec!["model".to_string()];
+        for alignment_move in &self.moves {
+            let log = alignment_move.get_log_letter()
+                .map_or(NFA_WORD_ALIGNMENT_NO_MOVE_SYMBOL.to_string(), |l| printer.get_letter_string_repr(&l));
+            let model = alignment_move.get_model_letter()
+                .map_or(NFA_WORD_ALIGNMENT_NO_MOVE_SYMBOL.to_string(), |l| printer.get_letter_string_repr(&l));
+            log_row.push(log);
+            model_row.push(model);
+        }
+        for (log,model) in log_row.iter_mut().zip(model_row.iter_mut()) {
+            let width = log.chars().count().max(model.chars().count());
+            *log = format!("{:<width$}",log,width=width);
+            *model = format!("{:<width$}",model,width=width);
+        }
+        format!("{}\n{}",log_row.join(" | ").trim_end(),model_row.join(" | ").trim_end())
+    }
+}
@@ -0,0 +1,83 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::autali::moves::NfaWordAlignmentMove;
+
+
+/// Costs of the moves of an alignment.
+/// Synchronous moves are free while log and model moves have a default cost which can be overridden for specific letters.
+#[derive(Debug, Clone)]
+pub struct NfaWordAlignmentCosts {
+    pub log_move : u32,
+    pub model_move : u32,
+    pub log_move_by_letter : HashMap<usize,u32>,
+    pub model_move_by_letter : HashMap<usize,u32>
+}
+
+impl NfaWordAlignmentCosts {
+    pub fn new(log_move: u32, model_move: u32) -> Self {
+        Self { log_move, model_move, log_move_by_letter : HashMap::new(), model_move_by_letter : HashMap::new() }
+    }
+
+    pub fn with_log_move_cost(mut self, letter : usize, cost : u32) -> Self {
+        self.log_move_by_letter.insert(letter,cost);
+        self
+    }
+
+    pub fn with_model_move_cost(mut self, letter : usize, cost : u32) -> Self {
+        self.model_move_by_letter.insert(letter,cost);
+        self
+    }
+
+    pub fn get_move_cost(&self, alignment_move : &NfaWordAlignmentMove) -> u32 {
+        match alignment_move {
+            NfaWordAlignmentMove::Synchronous(_) => {
+                0
+            },
+            NfaWordAlignmentMove::LogOnly(letter) => {
+                *self.log_move_by_letter.get(letter).unwrap_or(&self.log_move)
+            },
+            NfaWordAlignmentMove::ModelOnly(letter) => {
+                *self.model_move_by_letter.get(letter).unwrap_or(&self.model_move)
+            }
+        }
+    }
+
+    /// Returns a lower bound on the cost of any model move.
+    pub fn get_min_model_move_cost(&self) -> u32 {
+        self.model_move_by_letter.values().fold(self.model_move, |min, cost| min.min(*cost))
+    }
+}
+
+impl Default for NfaWordAlignmentCosts {
+    /// Standard cost function: log and model moves cost 1.
+    fn default() -> Self {
+        NfaWordAlignmentCosts::new(1,1)
+    }
+}
+
+impl fmt::Display for NfaWordAlignmentCosts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,"[log move={:},model move={:}]",
+               self.log_move,
+               self.model_move)
+    }
+}
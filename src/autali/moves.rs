@@ -0,0 +1,73 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+
+/// A move of an alignment between a word (the log) and a run of the NFA (the model).
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaWordAlignmentMove {
+    // the letter is read both in the word and in the NFA
+    Synchronous(usize),
+    // the letter of the word is not read in the NFA
+    LogOnly(usize),
+    // the NFA reads a letter which does not occur in the word
+    ModelOnly(usize)
+}
+
+impl NfaWordAlignmentMove {
+    pub fn get_log_letter(&self) -> Option<usize> {
+        match self {
+            NfaWordAlignmentMove::Synchronous(letter) |
+            NfaWordAlignmentMove::LogOnly(letter) => {
+                Some(*letter)
+            },
+            NfaWordAlignmentMove::ModelOnly(_) => {
+                None
+            }
+        }
+    }
+
+    pub fn get_model_letter(&self) -> Option<usize> {
+        match self {
+            NfaWordAlignmentMove::Synchronous(letter) |
+            NfaWordAlignmentMove::ModelOnly(letter) => {
+                Some(*letter)
+            },
+            NfaWordAlignmentMove::LogOnly(_) => {
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for NfaWordAlignmentMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaWordAlignmentMove::Synchronous(_) => {
+                write!(f,"synchronous move")
+            },
+            NfaWordAlignmentMove::LogOnly(_) => {
+                write!(f,"log move")
+            },
+            NfaWordAlignmentMove::ModelOnly(_) => {
+                write!(f,"model move")
+            }
+        }
+    }
+}
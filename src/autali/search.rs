@@ -0,0 +1,138 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autali::alignment::NfaWordAlignment;
+use crate::autali::costs::NfaWordAlignmentCosts;
+use crate::autali::moves::NfaWordAlignmentMove;
+use crate::autana::explanation::get_shortest_completion;
+use crate::autana::node::{get_epsilon_closure, get_successors};
+
+
+type NfaWordAlignmentConfiguration = (BTreeSet<usize>,usize);
+
+/// Computes an optimal alignment of the word with the NFA using A*.
+/// Returns None if the NFA accepts no word.
+pub fn get_optimal_alignment(nfa : &AutNFAIT<usize>,
+                             word : &[usize],
+                             costs : &NfaWordAlignmentCosts) -> Option<NfaWordAlignment> {
+    let (moves,cost) = search_alignment(nfa,word,costs)?;
+    let (_,model_cost) = search_alignment(nfa,&[],costs)?;
+    let log_cost : u32 = word.iter()
+        .map(|letter| costs.get_move_cost(&NfaWordAlignmentMove::LogOnly(*letter)))
+        .sum();
+    Some(NfaWordAlignment::new(moves,cost,log_cost + model_cost))
+}
+
+/// A* search over the configurations (set of active states, position in the word).
+fn search_alignment(nfa : &AutNFAIT<usize>,
+                    word : &[usize],
+                    costs : &NfaWordAlignmentCosts) -> Option<(Vec<NfaWordAlignmentMove>,u32)> {
+    let initials : BTreeSet<usize> = nfa.initials.iter().cloned().collect();
+    let init : NfaWordAlignmentConfiguration = (get_epsilon_closure(nfa,&initials),0);
+    // configurations are numbered so that the heap only contains integers
+    let mut configurations : Vec<NfaWordAlignmentConfiguration> = vec![init.clone()];
+    let mut ids : HashMap<NfaWordAlignmentConfiguration,usize> = HashMap::new();
+    ids.insert(init,0);
+    let mut best_costs : Vec<u32> = vec![0];
+    let mut parents : Vec<Option<(usize,NfaWordAlignmentMove)>> = vec![None];
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((get_heuristic(nfa,word,costs,&configurations[0]),0,0)));
+    while let Some(Reverse((_,cost,id))) = heap.pop() {
+        if cost > best_costs[id] {
+            // outdated entry
+            continue;
+        }
+        let (states,pos) = configurations[id].clone();
+        if pos == word.len() && states.iter().any(|st| nfa.finals.contains(st)) {
+            let mut moves = vec![];
+            let mut current = id;
+            while let Some((parent,alignment_move)) = &parents[current] {
+                moves.push(alignment_move.clone());
+                current = *parent;
+            }
+            moves.reverse();
+            return Some((moves,cost));
+        }
+        let mut successors : Vec<(NfaWordAlignmentMove,NfaWordAlignmentConfiguration)> = vec![];
+        let enabled : BTreeSet<usize> = states.iter()
+            .flat_map(|st| nfa.transitions[*st].keys().cloned())
+            .collect();
+        if let Some(letter) = word.get(pos) {
+            if enabled.contains(letter) {
+                successors.push((NfaWordAlignmentMove::Synchronous(*letter),(get_successors(nfa,&states,letter),pos + 1)));
+            }
+            successors.push((NfaWordAlignmentMove::LogOnly(*letter),(states.clone(),pos + 1)));
+        }
+        for letter in &enabled {
+            successors.push((NfaWordAlignmentMove::ModelOnly(*letter),(get_successors(nfa,&states,letter),pos)));
+        }
+        for (alignment_move,configuration) in successors {
+            let new_cost = cost + costs.get_move_cost(&alignment_move);
+            let new_id = match ids.get(&configuration) {
+                Some(known_id) => {
+                    if new_cost >= best_costs[*known_id] {
+                        continue;
+                    }
+                    best_costs[*known_id] = new_cost;
+                    parents[*known_id] = Some((id,alignment_move));
+                    *known_id
+                },
+                None => {
+                    let new_id = configurations.len();
+                    ids.insert(configuration.clone(),new_id);
+                    configurations.push(configuration);
+                    best_costs.push(new_cost);
+                    parents.push(Some((id,alignment_move)));
+                    new_id
+                }
+            };
+            let estimate = new_cost + get_heuristic(nfa,word,costs,&configurations[new_id]);
+            heap.push(Reverse((estimate,new_cost,new_id)));
+        }
+    }
+    None
+}
+
+/// Admissible estimation of the cost required to complete an alignment from a configuration.
+/// Letters which label no transition of the NFA can only be log moves.
+/// If only such letters remain, the NFA must moreover reach a final state with model moves alone.
+fn get_heuristic(nfa : &AutNFAIT<usize>,
+                 word : &[usize],
+                 costs : &NfaWordAlignmentCosts,
+                 configuration : &NfaWordAlignmentConfiguration) -> u32 {
+    let (states,pos) = configuration;
+    let mut estimate = 0;
+    let mut only_unknown_letters = true;
+    for letter in &word[*pos..] {
+        if nfa.transitions.iter().any(|outgoing| outgoing.contains_key(letter)) {
+            only_unknown_letters = false;
+        } else {
+            estimate += costs.get_move_cost(&NfaWordAlignmentMove::LogOnly(*letter));
+        }
+    }
+    if only_unknown_letters && !states.iter().any(|st| nfa.finals.contains(st)) {
+        if let Some(completion) = get_shortest_completion(nfa,states) {
+            estimate += costs.get_min_model_move_cost() * (completion.len() as u32);
+        }
+    }
+    estimate
+}
@@ -0,0 +1,115 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+use autour_core::traits::transform::AutTransformable;
+
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisResetOn};
+use crate::autana::run::analyze_word;
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
+
+
+/// Where the analysis of a word from both ends locates its faults.
+/// Positions range from 0 to the length of the word, the latter standing for the end of the word.
+pub struct NfaWordAnalysisFaultLocalization {
+    pub forward_verdict : NfaWordAnalysisGlobalVerdict,
+    pub backward_verdict : NfaWordAnalysisGlobalVerdict,
+    // position of the first deviation met when reading the word from its start
+    pub forward_bound : Option<usize>,
+    // position of the first deviation met when reading the word from its end
+    pub backward_bound : Option<usize>
+}
+
+impl NfaWordAnalysisFaultLocalization {
+    pub fn new(forward_verdict: NfaWordAnalysisGlobalVerdict,
+               backward_verdict: NfaWordAnalysisGlobalVerdict,
+               forward_bound: Option<usize>,
+               backward_bound: Option<usize>) -> Self {
+        Self { forward_verdict, backward_verdict, forward_bound, backward_bound }
+    }
+
+    /// Returns the interval of positions (bounds included) in which a single fault must lie.
+    /// The prefix before the interval is read forward and the suffix after it is read backward without deviation.
+    /// There is no such interval if the word conforms or if the bounds cross, in which case the word has several faults.
+    pub fn get_window(&self) -> Option<(usize,usize)> {
+        match (self.backward_bound,self.forward_bound) {
+            (Some(start),Some(end)) if start <= end => {
+                Some((start,end))
+            },
+            _ => {
+                None
+            }
+        }
+    }
+}
+
+/// Returns the NFA which accepts the reversed words of the given one.
+pub fn get_reversed_nfa(nfa : &AutNFAIT<usize>) -> AutNFAIT<usize> {
+    let mut epsilon_trans = vec![HashSet::new();nfa.epsilon_trans.len()];
+    for (origin,targets) in nfa.epsilon_trans.iter().enumerate() {
+        for target in targets {
+            epsilon_trans[*target].insert(origin);
+        }
+    }
+    let mut reversed = nfa.clone().reverse();
+    reversed.epsilon_trans = epsilon_trans;
+    reversed
+}
+
+/// Returns the position of the first deviation along the path,
+/// or the length of the word if it is read entirely without reaching a final state.
+fn get_first_deviation(path : &NfaWordAnalysisPath, word_len : usize) -> Option<usize> {
+    if let Some((pos_in_trace,_)) = path.get_resynchronizations().first() {
+        return Some(*pos_in_trace);
+    }
+    match path.verdict {
+        NfaWordAnalysisLocalVerdict::EmptiedTrace => {
+            None
+        },
+        NfaWordAnalysisLocalVerdict::PrematureEnd => {
+            Some(word_len)
+        },
+        NfaWordAnalysisLocalVerdict::Deviation(_) | NfaWordAnalysisLocalVerdict::FailureToEmptyTrace => {
+            Some(path.get_last_node().pos_in_trace)
+        }
+    }
+}
+
+/// Analyzes the word forward against the NFA and backward against the reversed NFA
+/// and intersects the positions the two analyses blame.
+/// The backward analysis starts from the initial states of the reversed NFA (i.e. the final states of the NFA)
+/// whatever the start_on states of the parameterization, of which it only keeps the policy.
+pub fn localize_fault<Printer : AbstractLanguagePrinter<usize> + Clone + 'static>(nfa : &AutNFAIT<usize>,
+                                                                                  printer : Printer,
+                                                                                  word : &[usize],
+                                                                                  param : &NfaWordAnalysisParameterization)
+            -> NfaWordAnalysisFaultLocalization {
+    let (forward_verdict,forward_path) = analyze_word(nfa,printer.clone(),word.to_vec(),param);
+    let reversed_word : Vec<usize> = word.iter().rev().cloned().collect();
+    let backward_param = NfaWordAnalysisParameterization::new(NfaWordAnalysisResetOn::Initials,param.policy.clone());
+    let (backward_verdict,backward_path) = analyze_word(&get_reversed_nfa(nfa),printer,reversed_word,&backward_param);
+    let forward_bound = forward_path.and_then(|path| get_first_deviation(&path,word.len()));
+    // a deviation at the i-th letter from the end blames the letter just before the suffix which was read
+    let backward_bound = backward_path.and_then(|path| get_first_deviation(&path,word.len()))
+        .map(|reversed_pos| word.len().saturating_sub(reversed_pos + 1));
+    NfaWordAnalysisFaultLocalization::new(forward_verdict,backward_verdict,forward_bound,backward_bound)
+}
@@ -15,18 +15,58 @@ limitations under the License.
 */
 
 
+use std::collections::BTreeSet;
+
 use autour_core::nfa::nfa::AutNFA;
+use autour_core::nfait::nfait::AutNFAIT;
 use autour_core::traits::repr::AbstractLanguagePrinter;
+use autour_core::traits::translate::AutTranslatable;
+
+use crate::autana::node::NfaWordAnalysisNodeKind;
+use crate::autana::observation::NfaWordAnalysisObservation;
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisResetOn};
 
 
 pub struct NfaWordAnalysisContext<Printer : AbstractLanguagePrinter<usize>> {
-    pub nfa : AutNFA<usize>,
+    pub nfa : AutNFAIT<usize>,
     pub printer : Printer,
-    pub word : Vec<usize>
+    pub word : Vec<NfaWordAnalysisObservation>
 }
 
 impl<Printer: AbstractLanguagePrinter<usize>> NfaWordAnalysisContext<Printer> {
     pub fn new(nfa: AutNFA<usize>, printer: Printer, word: Vec<usize>) -> Self {
+        Self::from_nfait(nfa.to_nfait(), printer, word)
+    }
+
+    /// The analyzed automaton may contain epsilon transitions.
+    pub fn from_nfait(nfa: AutNFAIT<usize>, printer: Printer, word: Vec<usize>) -> Self {
+        Self::from_observations(nfa, printer, word.into_iter().map(NfaWordAnalysisObservation::from).collect())
+    }
+
+    /// Some positions of the word may only be known up to a set of possible letters.
+    pub fn from_observations(nfa: AutNFAIT<usize>, printer: Printer, word: Vec<NfaWordAnalysisObservation>) -> Self {
         Self { nfa, printer, word }
     }
+
+    fn get_candidates(&self, observations : &[NfaWordAnalysisObservation]) -> Vec<BTreeSet<usize>> {
+        observations.iter().map(|obs| obs.get_candidates(&self.nfa)).collect()
+    }
+
+    /// Returns the states on which to reset knowing the observations preceding the deviation
+    /// and those from which the word is to be read after the reset.
+    pub fn get_reset_states(&self,
+                            reset : &NfaWordAnalysisResetOn,
+                            history_len : usize,
+                            upcoming_from : usize) -> BTreeSet<usize> {
+        let (history,upcoming) = reset.get_lookaround(&self.word[..history_len],&self.word[upcoming_from..]);
+        reset.get_reset_states_on_candidates(&self.nfa,
+                                             &self.get_candidates(history),
+                                             &self.get_candidates(upcoming))
+    }
+
+    pub fn make_init_node(&self, param : &NfaWordAnalysisParameterization) -> NfaWordAnalysisNodeKind {
+        NfaWordAnalysisNodeKind::from_reached_states(&self.nfa,
+                                                     self.get_reset_states(&param.start_on,0,0),
+                                                     0)
+    }
 }
@@ -0,0 +1,101 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::run::{analyze_word, get_path_verdict};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+
+
+/// The analysis of the events of a single session of an interleaved log.
+pub struct NfaWordAnalysisSessionReport<Key> {
+    pub key : Key,
+    // position in the global log of each letter of the session's word
+    pub positions : Vec<usize>,
+    pub word : Vec<usize>,
+    // metrics of the best path, so that they match the positions of its deviations
+    pub verdict : NfaWordAnalysisGlobalVerdict,
+    pub best_path : Option<NfaWordAnalysisPath>
+}
+
+impl<Key> NfaWordAnalysisSessionReport<Key> {
+    pub fn new(key: Key,
+               positions: Vec<usize>,
+               word: Vec<usize>,
+               best_path: Option<NfaWordAnalysisPath>) -> Self {
+        let verdict = get_path_verdict(&best_path);
+        Self { key, positions, word, verdict, best_path }
+    }
+
+    /// Returns the positions in the global log of the letters at which the best path deviates.
+    pub fn get_deviation_positions(&self) -> Vec<usize> {
+        match &self.best_path {
+            None => {
+                vec![]
+            },
+            Some(path) => {
+                path.get_resynchronizations().iter()
+                    .map(|(pos_in_trace,_)| self.positions[*pos_in_trace])
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<Key : fmt::Display> fmt::Display for NfaWordAnalysisSessionReport<Key> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let deviations : Vec<String> = self.get_deviation_positions().iter().map(|pos| pos.to_string()).collect();
+        if deviations.is_empty() {
+            write!(f,"session {:} : {:}",self.key,self.verdict)
+        } else {
+            write!(f,"session {:} : {:} (deviating at {})",self.key,self.verdict,deviations.join(", "))
+        }
+    }
+}
+
+/// Splits a log of events tagged with a correlation key into one word per key.
+/// Each word comes with the positions of its letters in the log.
+pub fn demultiplex<Key : Ord + Clone>(events : &[(Key,usize)]) -> BTreeMap<Key,(Vec<usize>,Vec<usize>)> {
+    let mut sessions : BTreeMap<Key,(Vec<usize>,Vec<usize>)> = BTreeMap::new();
+    for (pos,(key,letter)) in events.iter().enumerate() {
+        let (positions,word) = sessions.entry(key.clone()).or_default();
+        positions.push(pos);
+        word.push(*letter);
+    }
+    sessions
+}
+
+/// Analyzes the word of each session of the log independently, in the order of the keys.
+pub fn analyze_sessions<Key : Ord + Clone,
+                        Printer : AbstractLanguagePrinter<usize> + Clone + 'static>(nfa : &AutNFAIT<usize>,
+                                                                                    printer : &Printer,
+                                                                                    events : &[(Key,usize)],
+                                                                                    param : &NfaWordAnalysisParameterization)
+            -> Vec<NfaWordAnalysisSessionReport<Key>> {
+    demultiplex(events).into_iter()
+        .map(|(key,(positions,word))| {
+            let (_,best_path) = analyze_word(nfa,printer.clone(),word.clone(),param);
+            NfaWordAnalysisSessionReport::new(key,positions,word,best_path)
+        }).collect()
+}
@@ -0,0 +1,125 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::run::{analyze_word, get_path_verdict};
+use crate::autana::step::NfaWordAnalysisStepKind;
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+
+
+/// A trace of a corpus whose best explanation differs between two versions of a specification.
+pub struct NfaWordAnalysisDifference {
+    // index of the trace in the corpus
+    pub trace_index : usize,
+    // metrics of the best paths of the two analyses
+    pub old_verdict : NfaWordAnalysisGlobalVerdict,
+    pub new_verdict : NfaWordAnalysisGlobalVerdict,
+    // first position in the trace at which the best paths of the two analyses take different steps
+    pub divergence : Option<usize>
+}
+
+impl NfaWordAnalysisDifference {
+    pub fn new(trace_index: usize,
+               old_verdict: NfaWordAnalysisGlobalVerdict,
+               new_verdict: NfaWordAnalysisGlobalVerdict,
+               divergence: Option<usize>) -> Self {
+        Self { trace_index, old_verdict, new_verdict, divergence }
+    }
+}
+
+impl fmt::Display for NfaWordAnalysisDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,"trace #{:} : {:} became {:}",self.trace_index,self.old_verdict,self.new_verdict)?;
+        match self.divergence {
+            None => {
+                Ok(())
+            },
+            Some(pos) => {
+                write!(f," (diverging at position {:})",pos)
+            }
+        }
+    }
+}
+
+/// States of different NFAs cannot be compared hence reading steps are all considered the same.
+fn take_same_step(step : &NfaWordAnalysisStepKind, other : &NfaWordAnalysisStepKind) -> bool {
+    match (step,other) {
+        (NfaWordAnalysisStepKind::ReadNext(_),NfaWordAnalysisStepKind::ReadNext(_)) => {
+            true
+        },
+        _ => {
+            step == other
+        }
+    }
+}
+
+/// Returns the first position in the word at which the two paths take different steps, if any.
+pub fn get_divergence(path : &NfaWordAnalysisPath, other : &NfaWordAnalysisPath) -> Option<usize> {
+    let mut pos_in_trace = path.init_node.pos_in_trace;
+    for (idx,(step,node)) in path.steps.iter().enumerate() {
+        match other.steps.get(idx) {
+            Some((other_step,_)) if take_same_step(step,other_step) => {
+                pos_in_trace = node.pos_in_trace;
+            },
+            _ => {
+                return Some(pos_in_trace);
+            }
+        }
+    }
+    if other.steps.len() > path.steps.len() {
+        Some(pos_in_trace)
+    } else {
+        None
+    }
+}
+
+/// Analyzes every trace of the corpus against the old and the new NFA with the same parameterization
+/// and returns the traces whose best paths have different outcomes.
+/// The global verdicts are not compared as they sum the deviations of all the branches of the analyses.
+pub fn compare_specifications<Printer : AbstractLanguagePrinter<usize> + Clone + 'static>(old_nfa : &AutNFAIT<usize>,
+                                                                                          new_nfa : &AutNFAIT<usize>,
+                                                                                          printer : &Printer,
+                                                                                          corpus : &[Vec<usize>],
+                                                                                          param : &NfaWordAnalysisParameterization)
+            -> Vec<NfaWordAnalysisDifference> {
+    let mut differences = vec![];
+    for (trace_index,word) in corpus.iter().enumerate() {
+        let (_,old_path) = analyze_word(old_nfa,printer.clone(),word.clone(),param);
+        let (_,new_path) = analyze_word(new_nfa,printer.clone(),word.clone(),param);
+        let old_verdict = get_path_verdict(&old_path);
+        let new_verdict = get_path_verdict(&new_path);
+        if old_verdict != new_verdict {
+            let divergence = match (&old_path,&new_path) {
+                (Some(old_path),Some(new_path)) => {
+                    get_divergence(old_path,new_path)
+                },
+                _ => {
+                    None
+                }
+            };
+            differences.push(NfaWordAnalysisDifference::new(trace_index,old_verdict,new_verdict,divergence));
+        }
+    }
+    differences
+}
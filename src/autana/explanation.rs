@@ -0,0 +1,162 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::letter::AutLetter;
+
+use crate::autana::node::{get_epsilon_closure, get_successors, NfaWordAnalysisNodeKind};
+use crate::autana::observation::NfaWordAnalysisObservation;
+
+
+/// A letter which could have been read instead of the one causing a deviation.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NfaWordAnalysisExpectedLetter {
+    pub letter : usize,
+    // shortest continuation starting with the letter that reaches a final state, if any
+    pub completion : Option<Vec<usize>>
+}
+
+impl NfaWordAnalysisExpectedLetter {
+    pub fn new(letter: usize, completion: Option<Vec<usize>>) -> Self {
+        Self { letter, completion }
+    }
+}
+
+/// Explains a deviation by the letters that were expected at that point.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NfaWordAnalysisDeviationExplanation {
+    pub pos_in_trace : usize,
+    // what was observed and could not be read
+    pub observation : NfaWordAnalysisObservation,
+    pub expected : Vec<NfaWordAnalysisExpectedLetter>
+}
+
+impl NfaWordAnalysisDeviationExplanation {
+    pub fn new(pos_in_trace: usize, observation: NfaWordAnalysisObservation, expected: Vec<NfaWordAnalysisExpectedLetter>) -> Self {
+        Self { pos_in_trace, observation, expected }
+    }
+
+    /// Returns the explanation of the deviation occurring at the node, if the next letter cannot be read from it.
+    pub fn from_node(nfa : &AutNFAIT<usize>,
+                     word : &[NfaWordAnalysisObservation],
+                     node : &NfaWordAnalysisNodeKind) -> Option<Self> {
+        let observation = word.get(node.pos_in_trace)?;
+        if observation.get_candidates(nfa).iter().all(|letter| get_successors(nfa,&node.active_states,letter).is_empty()) {
+            let expected = get_expected_letters(nfa,&node.active_states).into_iter()
+                .map(|(letter,completion)| NfaWordAnalysisExpectedLetter::new(letter,completion))
+                .collect();
+            Some(NfaWordAnalysisDeviationExplanation::new(node.pos_in_trace,observation.clone(),expected))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the letters that can be read from the given states (sorted),
+/// each with the shortest continuation starting with it that reaches a final state, if any.
+pub fn get_expected_letters<Letter : AutLetter>(nfa : &AutNFAIT<Letter>,
+                                                states : &BTreeSet<usize>) -> Vec<(Letter,Option<Vec<Letter>>)> {
+    let mut enabled : BTreeSet<Letter> = BTreeSet::new();
+    for state in states {
+        if let Some(outgoing) = nfa.transitions.get(*state) {
+            enabled.extend(outgoing.iter().filter(|(_,targets)| !targets.is_empty()).map(|(letter,_)| *letter));
+        }
+    }
+    enabled.into_iter().map(|letter| {
+        let completion = get_shortest_completion(nfa,&get_successors(nfa,states,&letter))
+            .map(|mut suffix| {
+                suffix.insert(0,letter);
+                suffix
+            });
+        (letter,completion)
+    }).collect()
+}
+
+/// Returns the shortest word leading from one of the given states to a final state, if any.
+pub fn get_shortest_completion<Letter : AutLetter>(nfa : &AutNFAIT<Letter>,
+                                                   states : &BTreeSet<usize>) -> Option<Vec<Letter>> {
+    let mut visited : HashSet<usize> = HashSet::new();
+    // epsilon transitions do not lengthen the word hence are explored first
+    let mut queue : VecDeque<(usize,Vec<Letter>)> = states.iter().map(|st| (*st,vec![])).collect();
+    while let Some((state,word)) = queue.pop_front() {
+        if !visited.insert(state) {
+            continue;
+        }
+        if nfa.finals.contains(&state) {
+            return Some(word);
+        }
+        if let Some(targets) = nfa.epsilon_trans.get(state) {
+            for target in targets {
+                queue.push_front((*target,word.clone()));
+            }
+        }
+        if let Some(outgoing) = nfa.transitions.get(state) {
+            let mut letters : Vec<&Letter> = outgoing.keys().collect();
+            letters.sort();
+            for letter in letters {
+                let mut targets : Vec<&usize> = outgoing.get(letter).unwrap().iter().collect();
+                targets.sort();
+                for target in targets {
+                    let mut new_word = word.clone();
+                    new_word.push(*letter);
+                    queue.push_back((*target,new_word));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns up to k shortest distinct words leading from the given states to a final state,
+/// ordered by length.
+pub fn get_shortest_completions<Letter : AutLetter>(nfa : &AutNFAIT<Letter>,
+                                                    states : &BTreeSet<usize>,
+                                                    k : usize) -> Vec<Vec<Letter>> {
+    let mut alphabet : Vec<Letter> = nfa.alphabet.iter().cloned().collect();
+    alphabet.sort();
+    let mut completions = vec![];
+    // words are explored by increasing length, each being associated to the set of states it reaches
+    // a set of states needs not be expanded more than k times because
+    // continuations from later expansions would be longer than those from the first k ones
+    let mut expansions : HashMap<BTreeSet<usize>,usize> = HashMap::new();
+    let mut queue : VecDeque<(BTreeSet<usize>,Vec<Letter>)> = VecDeque::new();
+    queue.push_back((get_epsilon_closure(nfa,states),vec![]));
+    while let Some((reached,word)) = queue.pop_front() {
+        if completions.len() >= k {
+            break;
+        }
+        let expanded = expansions.entry(reached.clone()).or_insert(0);
+        if *expanded >= k {
+            continue;
+        }
+        *expanded += 1;
+        if reached.iter().any(|st| nfa.finals.contains(st)) {
+            completions.push(word.clone());
+        }
+        for letter in &alphabet {
+            let next = get_successors(nfa,&reached,letter);
+            if !next.is_empty() {
+                let mut new_word = word.clone();
+                new_word.push(*letter);
+                queue.push_back((next,new_word));
+            }
+        }
+    }
+    completions
+}
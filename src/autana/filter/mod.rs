@@ -16,4 +16,5 @@ limitations under the License.
 
 
 pub mod elim;
+#[allow(clippy::module_inception)]
 pub mod filter;
\ No newline at end of file
@@ -16,7 +16,6 @@ limitations under the License.
 
 use std::collections::{BTreeSet, HashSet};
 
-use autour_core::traits::run::AutRunnable;
 use autour_core::traits::repr::AbstractLanguagePrinter;
 
 use graph_process_manager_core::delegate::node::GenericNode;
@@ -26,8 +25,10 @@ use graph_process_manager_core::queued_steps::step::GenericStep;
 use crate::autana::conf::{NfaWordAnalysisConfig, NfaWordAnalysisStaticLocalVerdictAnalysisProof};
 use crate::autana::context::NfaWordAnalysisContext;
 use crate::autana::filter::filter::NfaWordAnalysisFilterCriterion;
-use crate::autana::node::NfaWordAnalysisNodeKind;
-use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisPolicy};
+use crate::autana::handling::policy::{classify_deviation, get_policy_steps};
+use crate::autana::node::{get_epsilon_closure, get_successors, NfaWordAnalysisNodeKind};
+use crate::autana::observation::NfaWordAnalysisObservation;
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisResetOn};
 use crate::autana::step::NfaWordAnalysisStepKind;
 use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
 
@@ -45,22 +46,48 @@ impl<Printer : AbstractLanguagePrinter<usize>>
                         _node_counter: u32) -> NfaWordAnalysisNodeKind {
         match &step_to_process.kind {
             NfaWordAnalysisStepKind::ReadNext(new_active) => {
-                NfaWordAnalysisNodeKind::new(new_active.clone(),
-                                              parent_state.kind.pos_in_trace + 1)
+                NfaWordAnalysisNodeKind::from_reached_states(&context.nfa,
+                                                             new_active.clone(),
+                                                             parent_state.kind.pos_in_trace + 1)
             },
             NfaWordAnalysisStepKind::ResetAndOrSkip(may_reset,may_skip) => {
-                let new_active = if *may_reset {
-                        param.policy.get_reset_policy().unwrap().get_reset_states(&context.nfa)
-                    } else {
-                        parent_state.kind.active_states.clone()
-                    };
                 let new_pos = if *may_skip {
                     parent_state.kind.pos_in_trace + 1
                 } else {
                     parent_state.kind.pos_in_trace
                 };
-                NfaWordAnalysisNodeKind::new(new_active,
-                                             new_pos)
+                if *may_reset {
+                    // reset targets are computed w.r.t. the letters preceding the deviation
+                    // and those remaining after the skip, if any
+                    let reset_states = context.get_reset_states(param.policy.get_reset_policy().unwrap(),
+                                                                parent_state.kind.pos_in_trace,
+                                                                new_pos);
+                    NfaWordAnalysisNodeKind::from_reached_states(&context.nfa,
+                                                                 reset_states,
+                                                                 new_pos)
+                } else {
+                    NfaWordAnalysisNodeKind::new_with_closure(parent_state.kind.active_states.clone(),
+                                                              parent_state.kind.closure_states.clone(),
+                                                              new_pos)
+                }
+            },
+            NfaWordAnalysisStepKind::ReadSwapped(distance,new_active) => {
+                NfaWordAnalysisNodeKind::from_reached_states(&context.nfa,
+                                                             new_active.clone(),
+                                                             parent_state.kind.pos_in_trace + distance + 1)
+            },
+            NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(alternative,may_skip) => {
+                let new_pos = if *may_skip {
+                    parent_state.kind.pos_in_trace + 1
+                } else {
+                    parent_state.kind.pos_in_trace
+                };
+                let reset_states = context.get_reset_states(param.policy.get_reset_alternative(*alternative).unwrap(),
+                                                            parent_state.kind.pos_in_trace,
+                                                            new_pos);
+                NfaWordAnalysisNodeKind::from_reached_states(&context.nfa,
+                                                             reset_states,
+                                                             new_pos)
             }
         }
     }
@@ -85,49 +112,33 @@ impl<Printer : AbstractLanguagePrinter<usize>>
                 // i.e. the trace is already emptied
                 vec![]
             },
-            Some( letter) => {
-                // here we have the letter which is to be read in the NFA
+            Some(observation) => {
+                // here we have the letter, or the possible letters, which is to be read in the NFA
                 // from the current set of active states
                 let as_hashset : HashSet<usize> = parent_node_kind.active_states.iter().cloned().collect();
-                let new_active = context.nfa.run_transition(&as_hashset,letter).unwrap();
+                let new_active = observation.run_transition(&context.nfa,&as_hashset);
                 if new_active.is_empty() {
                     // here the letter leads nowhere
-                    // hence we may either reset the NFA and/or skip the letter
-                    match &param.policy {
-                        NfaWordAnalysisPolicy::StopAtDeviation => {
-                            vec![]
-                        },
-                        NfaWordAnalysisPolicy::SkipAndMayReset(may_reset) => {
-                            vec![NfaWordAnalysisStepKind::ResetAndOrSkip(may_reset.is_some(),true)]
+                    // we may either reset the NFA and/or skip the letter
+                    let policy_steps = match get_policy_steps(&param.policy,
+                                                              |reset| get_reset_active_states(context,parent_node_kind,reset),
+                                                              |reset_active| is_reset_of_use(context,parent_node_kind,reset_active,observation)) {
+                        None => {
+                            // the analysis stops whether or not the letters could be reordered
+                            return vec![];
                         },
-                        NfaWordAnalysisPolicy::TryResetThenMaySkip(reset,skip) => {
-                            let reset_active = reset.get_reset_states(&context.nfa);
-                            // ***
-                            if reset_active.is_subset(&parent_node_kind.active_states) {
-                                // if the set of active state in parent already includes the reset states then reset is useless
-                                if *skip {
-                                    vec![NfaWordAnalysisStepKind::ResetAndOrSkip(true,true)]
-                                } else {
-                                    vec![]
-                                }
-                            } else {
-                                // here reset may be of use
-                                let reset_active_as_hashset : HashSet<usize> = reset_active.into_iter().collect();
-                                let new_active_after_reset_and_run = context.nfa.run_transition(&reset_active_as_hashset,letter).unwrap();
-                                if new_active_after_reset_and_run.is_empty() {
-                                    // here reset did not allow running the letter
-                                    if *skip {
-                                        vec![NfaWordAnalysisStepKind::ResetAndOrSkip(true,true)]
-                                    } else {
-                                        vec![]
-                                    }
-                                } else {
-                                    // here reset allows running the letter and hence do not skip
-                                    vec![NfaWordAnalysisStepKind::ResetAndOrSkip(true,false)]
-                                }
-                            }
+                        Some(policy_steps) => {
+                            policy_steps
                         }
-                    }
+                    };
+                    // alongside the policy, if the letter can be read after one of the next letters, it may be swapped with it
+                    let mut steps : Vec<NfaWordAnalysisStepKind> = (1..=param.max_swap_distance)
+                        .filter_map(|distance| {
+                            read_swapped(context,parent_node_kind,distance)
+                                .map(|swapped_active| NfaWordAnalysisStepKind::ReadSwapped(distance,swapped_active))
+                        }).collect();
+                    steps.extend(policy_steps.into_iter().map(NfaWordAnalysisStepKind::from));
+                    steps
                 } else {
                     let as_btreeset : BTreeSet<usize> = new_active.into_iter().collect();
                     vec![NfaWordAnalysisStepKind::ReadNext(as_btreeset)]
@@ -141,8 +152,10 @@ impl<Printer : AbstractLanguagePrinter<usize>>
                                        node_kind: &NfaWordAnalysisNodeKind) -> NfaWordAnalysisLocalVerdict {
         if context.word.get(node_kind.pos_in_trace).is_some() {
             NfaWordAnalysisLocalVerdict::FailureToEmptyTrace
-        } else {
+        } else if node_kind.active_states.iter().any(|st| context.nfa.finals.contains(st)) {
             NfaWordAnalysisLocalVerdict::EmptiedTrace
+        } else {
+            NfaWordAnalysisLocalVerdict::PrematureEnd
         }
     }
 
@@ -151,10 +164,19 @@ impl<Printer : AbstractLanguagePrinter<usize>>
                                               node_kind: &mut NfaWordAnalysisNodeKind)
             -> Option<(NfaWordAnalysisLocalVerdict,NfaWordAnalysisStaticLocalVerdictAnalysisProof)> {
         let next = Self::collect_next_steps(context,param,node_kind);
-        if let Some(NfaWordAnalysisStepKind::ResetAndOrSkip(_,_)) = next.get(0) {
-            Some((NfaWordAnalysisLocalVerdict::Deviation,NfaWordAnalysisStaticLocalVerdictAnalysisProof{}))
-        } else {
-            None
+        match next.first() {
+            None | Some(NfaWordAnalysisStepKind::ReadNext(_)) => {
+                None
+            },
+            Some(_) => {
+                let candidates = context.word.get(node_kind.pos_in_trace).unwrap().get_candidates(&context.nfa);
+                // the deviation is classified as per the policy,
+                // reading the letter swapped with a later one is a reordering only along the paths which take that step
+                let kind = classify_deviation(&param.policy,
+                                              context.nfa.transitions.iter().any(|outgoing| candidates.iter().any(|letter| outgoing.contains_key(letter))),
+                                              next.iter().any(|step| step.skips_letter()));
+                Some((NfaWordAnalysisLocalVerdict::Deviation(kind),NfaWordAnalysisStaticLocalVerdictAnalysisProof{}))
+            }
         }
     }
 
@@ -163,4 +185,51 @@ impl<Printer : AbstractLanguagePrinter<usize>>
                                            _loc_verd: &NfaWordAnalysisLocalVerdict) -> bool {
         true
     }
-}
\ No newline at end of file
+}
+
+
+/// Returns the active states on which the NFA would be reset when a deviation occurs at the given node.
+fn get_reset_active_states<Printer : AbstractLanguagePrinter<usize>>(context: &NfaWordAnalysisContext<Printer>,
+                                                                     node_kind: &NfaWordAnalysisNodeKind,
+                                                                     reset : &NfaWordAnalysisResetOn) -> BTreeSet<usize> {
+    get_epsilon_closure(&context.nfa,
+                        &context.get_reset_states(reset,node_kind.pos_in_trace,node_kind.pos_in_trace))
+}
+
+/// A reset is of use if it changes the active states and allows reading the offending letter
+/// (or any of the letters which may have occurred).
+fn is_reset_of_use<Printer : AbstractLanguagePrinter<usize>>(context: &NfaWordAnalysisContext<Printer>,
+                                                             node_kind: &NfaWordAnalysisNodeKind,
+                                                             reset_active : &BTreeSet<usize>,
+                                                             observation : &NfaWordAnalysisObservation) -> bool {
+    if reset_active.is_subset(&node_kind.active_states) {
+        // if the set of active state in parent already includes the reset states then reset is useless
+        false
+    } else {
+        // here reset may be of use if it allows running the letter
+        observation.get_candidates(&context.nfa).iter()
+            .any(|letter| !get_successors(&context.nfa,reset_active,letter).is_empty())
+    }
+}
+
+/// Reads the letter at the given distance from the position of the node first, then those in between and then the offending one.
+/// Returns the reached states if all could be read.
+fn read_swapped<Printer : AbstractLanguagePrinter<usize>>(context: &NfaWordAnalysisContext<Printer>,
+                                                          node_kind: &NfaWordAnalysisNodeKind,
+                                                          distance : usize) -> Option<BTreeSet<usize>> {
+    let pos = node_kind.pos_in_trace;
+    if pos + distance >= context.word.len() {
+        return None;
+    }
+    let mut order = vec![pos + distance];
+    order.extend(pos + 1..pos + distance);
+    order.push(pos);
+    let mut current : HashSet<usize> = node_kind.active_states.iter().cloned().collect();
+    for idx in order {
+        current = context.word[idx].run_transition(&context.nfa,&current);
+        if current.is_empty() {
+            return None;
+        }
+    }
+    Some(current.into_iter().collect())
+}
@@ -0,0 +1,108 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::autana::param::{NfaWordAnalysisPolicy, NfaWordAnalysisResetOn};
+use crate::autana::verdict::local::NfaWordAnalysisDeviationKind;
+
+
+/// The steps which the policy prescribes when the next letter cannot be read,
+/// whatever the active states or configurations from which the word is read.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaWordAnalysisPolicyStep {
+    // first arg if reset
+    // second arg if skip
+    ResetAndOrSkip(bool,bool),
+    // first arg is the index of the chosen reset alternative
+    // second arg if skip
+    ResetOnAlternativeAndOrSkip(usize,bool)
+}
+
+impl NfaWordAnalysisPolicyStep {
+    pub fn skips_letter(&self) -> bool {
+        match self {
+            NfaWordAnalysisPolicyStep::ResetAndOrSkip(_,skip) |
+            NfaWordAnalysisPolicyStep::ResetOnAlternativeAndOrSkip(_,skip) => {
+                *skip
+            }
+        }
+    }
+}
+
+/// Returns the steps which the policy prescribes on a deviation, or None if the analysis stops at deviations.
+/// The active states or configurations on which each reset would occur are given by get_reset_active
+/// and is_reset_of_use tells whether they allow reading the offending letter.
+pub fn get_policy_steps<Active : PartialEq>(policy : &NfaWordAnalysisPolicy,
+                                            get_reset_active : impl Fn(&NfaWordAnalysisResetOn) -> Active,
+                                            is_reset_of_use : impl Fn(&Active) -> bool) -> Option<Vec<NfaWordAnalysisPolicyStep>> {
+    match policy {
+        NfaWordAnalysisPolicy::StopAtDeviation => {
+            None
+        },
+        NfaWordAnalysisPolicy::SkipAndMayReset(may_reset) => {
+            Some(vec![NfaWordAnalysisPolicyStep::ResetAndOrSkip(may_reset.is_some(),true)])
+        },
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(reset,skip) => {
+            if is_reset_of_use(&get_reset_active(reset)) {
+                // here reset allows running the letter and hence do not skip
+                Some(vec![NfaWordAnalysisPolicyStep::ResetAndOrSkip(true,false)])
+            } else if *skip {
+                Some(vec![NfaWordAnalysisPolicyStep::ResetAndOrSkip(true,true)])
+            } else {
+                Some(vec![])
+            }
+        },
+        NfaWordAnalysisPolicy::BranchOnResets(alternatives,skip) => {
+            // alternatives which would reset on the same active states are explored only once
+            let mut distinct_alternatives : Vec<(usize,Active)> = vec![];
+            for (alternative,reset) in alternatives.iter().enumerate() {
+                let reset_active = get_reset_active(reset);
+                if !distinct_alternatives.iter().any(|(_,other)| *other == reset_active) {
+                    distinct_alternatives.push((alternative,reset_active));
+                }
+            }
+            let of_use : Vec<NfaWordAnalysisPolicyStep> = distinct_alternatives.iter()
+                .filter(|(_,reset_active)| is_reset_of_use(reset_active))
+                .map(|(alternative,_)| NfaWordAnalysisPolicyStep::ResetOnAlternativeAndOrSkip(*alternative,false))
+                .collect();
+            if !of_use.is_empty() {
+                Some(of_use)
+            } else if *skip {
+                Some(distinct_alternatives.iter()
+                    .map(|(alternative,_)| NfaWordAnalysisPolicyStep::ResetOnAlternativeAndOrSkip(*alternative,true))
+                    .collect())
+            } else {
+                Some(vec![])
+            }
+        }
+    }
+}
+
+/// Classifies a deviation as per the policy, knowing whether some transition is labelled by the offending letter
+/// and whether the policy skips it.
+pub fn classify_deviation(policy : &NfaWordAnalysisPolicy,
+                          is_known_letter : bool,
+                          skips_letter : bool) -> NfaWordAnalysisDeviationKind {
+    let reset_attempted = matches!(policy,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(_,_) | NfaWordAnalysisPolicy::BranchOnResets(_,_));
+    if !is_known_letter {
+        NfaWordAnalysisDeviationKind::UnknownLetter
+    } else if reset_attempted && skips_letter {
+        // with these policies the letter is only skipped if resetting did not allow reading it
+        NfaWordAnalysisDeviationKind::ResetIneffective
+    } else {
+        NfaWordAnalysisDeviationKind::UnexpectedLetter
+    }
+}
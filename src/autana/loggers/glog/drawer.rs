@@ -23,17 +23,52 @@ use graph_process_manager_loggers::graphviz::drawer::GraphVizProcessDrawer;
 use graph_process_manager_loggers::graphviz::format::GraphVizLoggerNodeFormat;
 use graphviz_dot_builder::colors::GraphvizColor;
 use graphviz_dot_builder::item::cluster::GraphVizCluster;
+use graphviz_dot_builder::item::item::GraphVizGraphItem;
 use graphviz_dot_builder::item::node::node::GraphVizNode;
 use graphviz_dot_builder::item::node::style::{GraphvizNodeStyleItem, GvNodeShape, GvNodeStyleKind};
 use graphviz_dot_builder::traits::{DotBuildable, DotPrintable, GraphVizOutputFormat};
 
 use crate::autana::conf::{NfaWordAnalysisConfig, NfaWordAnalysisStaticLocalVerdictAnalysisProof};
 use crate::autana::context::NfaWordAnalysisContext;
+use crate::autana::explanation::{get_shortest_completion, NfaWordAnalysisDeviationExplanation};
 use crate::autana::node::NfaWordAnalysisNodeKind;
 use crate::autana::param::NfaWordAnalysisParameterization;
 use crate::autana::step::NfaWordAnalysisStepKind;
-use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
-
+use crate::autana::verdict::local::{NfaWordAnalysisDeviationKind, NfaWordAnalysisLocalVerdict};
+
+
+pub const NFA_WORD_ANALYSIS_COLOR_CLOSURE_STATE: GraphvizColor = GraphvizColor::lightskyblue;
+
+/// Colors of the local verdicts, also used by the analyses which build upon this one.
+pub fn get_word_analysis_verdict_color(local_verdict: &NfaWordAnalysisLocalVerdict) -> GraphvizColor {
+    match local_verdict {
+        NfaWordAnalysisLocalVerdict::EmptiedTrace => {
+            GraphvizColor::green
+        },
+        NfaWordAnalysisLocalVerdict::Deviation(kind) => {
+            match kind {
+                NfaWordAnalysisDeviationKind::UnexpectedLetter => {
+                    GraphvizColor::orange
+                },
+                NfaWordAnalysisDeviationKind::UnknownLetter => {
+                    GraphvizColor::purple
+                },
+                NfaWordAnalysisDeviationKind::ResetIneffective => {
+                    GraphvizColor::brown
+                },
+                NfaWordAnalysisDeviationKind::Reordering => {
+                    GraphvizColor::darkgreen
+                }
+            }
+        },
+        NfaWordAnalysisLocalVerdict::FailureToEmptyTrace => {
+            GraphvizColor::red
+        },
+        NfaWordAnalysisLocalVerdict::PrematureEnd => {
+            GraphvizColor::gold
+        }
+    }
+}
 
 pub struct NfaWordAnalysisProcessDrawer {
     pub temp_folder : String
@@ -58,17 +93,7 @@ impl<Printer : AbstractLanguagePrinter<usize>>
 
     fn get_verdict_color(&self,
                          local_verdict: &NfaWordAnalysisLocalVerdict) -> GraphvizColor {
-        match local_verdict {
-            NfaWordAnalysisLocalVerdict::EmptiedTrace => {
-                GraphvizColor::green
-            },
-            NfaWordAnalysisLocalVerdict::Deviation => {
-                GraphvizColor::orange
-            },
-            NfaWordAnalysisLocalVerdict::FailureToEmptyTrace => {
-                GraphvizColor::red
-            }
-        }
+        get_word_analysis_verdict_color(local_verdict)
     }
 
     fn make_static_analysis_as_gvcluster(&self,
@@ -102,10 +127,22 @@ impl<Printer : AbstractLanguagePrinter<usize>>
         // draw NFA
         let nfa_name = format!("nfa{}",new_state_id);
         let as_hashset : HashSet<usize> = new_node.active_states.iter().cloned().collect();
-        context.nfa.to_dot(false,&as_hashset,&context.printer)
-            .print_dot(&[temp_folder.to_string()],
-                       &nfa_name,
-                       &GraphVizOutputFormat::png);
+        let mut nfa_digraph = context.nfa.to_dot(false,&as_hashset,&context.printer);
+        // states only reached via the epsilon closure are highlighted differently
+        for item in nfa_digraph.items.iter_mut() {
+            if let GraphVizGraphItem::Node(node) = item {
+                if new_node.closure_states.iter().any(|st| node.id == format!("S{}",st)) {
+                    for style_item in node.style.iter_mut() {
+                        if let GraphvizNodeStyleItem::FillColor(_) = style_item {
+                            *style_item = GraphvizNodeStyleItem::FillColor(NFA_WORD_ANALYSIS_COLOR_CLOSURE_STATE);
+                        }
+                    }
+                }
+            }
+        }
+        let _ = nfa_digraph.print_dot(&[temp_folder.to_string()],
+                                      &nfa_name,
+                                      &GraphVizOutputFormat::png);
         let nfa_image_file_path : PathBuf = [temp_folder, &format!("{}.png",nfa_name)].iter().collect();
         let style = vec![
             GraphvizNodeStyleItem::Image(nfa_image_file_path.into_os_string().to_str().unwrap().to_string()),
@@ -117,10 +154,40 @@ impl<Printer : AbstractLanguagePrinter<usize>>
         let remaining_trace_as_string : String = {
             if new_node.pos_in_trace < context.word.len() {
                 let as_letters : Vec<String> = context.word[new_node.pos_in_trace..]
-                    .iter().map(|l| context.printer.get_letter_string_repr(l)).collect();
-                format!("<- {}",as_letters.join(context.printer.get_concatenation_separator(false)))
-            } else {
+                    .iter().map(|obs| obs.to_string_with_printer(&context.printer)).collect();
+                let remaining = format!("<- {}",as_letters.join(context.printer.get_concatenation_separator(false)));
+                match NfaWordAnalysisDeviationExplanation::from_node(&context.nfa,&context.word,new_node) {
+                    None => {
+                        remaining
+                    },
+                    Some(explanation) => {
+                        let expected : Vec<String> = explanation.expected.iter()
+                            .map(|exp| context.printer.get_letter_string_repr(&exp.letter)).collect();
+                        if expected.is_empty() {
+                            format!("{} (expected: {})",remaining,context.printer.get_empty_symbol(false))
+                        } else {
+                            format!("{} (expected: {})",remaining,expected.join(", "))
+                        }
+                    }
+                }
+            } else if new_node.active_states.iter().any(|st| context.nfa.finals.contains(st)) {
                 format!("<- {}",context.printer.get_epsilon_symbol(true))
+            } else {
+                // the trace is emptied without reaching a final state
+                match get_shortest_completion(&context.nfa,&new_node.active_states) {
+                    None => {
+                        format!("<- {} (missing: {})",
+                                context.printer.get_epsilon_symbol(true),
+                                context.printer.get_empty_symbol(true))
+                    },
+                    Some(completion) => {
+                        let as_letters : Vec<String> = completion.iter()
+                            .map(|l| context.printer.get_letter_string_repr(l)).collect();
+                        format!("<- {} (missing: {})",
+                                context.printer.get_epsilon_symbol(true),
+                                as_letters.join(context.printer.get_concatenation_separator(false)))
+                    }
+                }
             }
         };
         let style = vec![
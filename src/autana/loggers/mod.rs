@@ -16,3 +16,4 @@ limitations under the License.
 
 
 pub mod glog;
+pub mod plog;
@@ -0,0 +1,245 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::autana::conf::{NfaWordAnalysisConfig, NfaWordAnalysisStaticLocalVerdictAnalysisProof};
+use crate::autana::context::NfaWordAnalysisContext;
+use crate::autana::explanation::{get_shortest_completions, NfaWordAnalysisDeviationExplanation};
+use crate::autana::filter::elim::NfaWordAnalysisFilterEliminationKind;
+use crate::autana::filter::filter::NfaWordAnalysisFilterCriterion;
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::node::NfaWordAnalysisNodeKind;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::priorities::NfaWordAnalysisPriorities;
+use crate::autana::step::NfaWordAnalysisStepKind;
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autana::verdict::local::{NfaWordAnalysisDeviationKind, NfaWordAnalysisLocalVerdict};
+
+
+/// Keeps the paths of the analysis graph in memory so that they can be inspected once the process terminates.
+pub struct NfaWordAnalysisPathsLogger {
+    // number of completions to suggest when the trace is emptied without reaching a final state
+    pub completions_num : usize,
+    // maps node ids to the nodes
+    nodes : HashMap<u32,NfaWordAnalysisNodeKind>,
+    // maps node ids to the id of the node from which they were first reached and the corresponding step
+    parents : HashMap<u32,(u32,NfaWordAnalysisStepKind)>,
+    // terminal nodes and their local verdicts
+    terminals : Vec<(u32,NfaWordAnalysisLocalVerdict)>,
+    // kinds of the deviations occurring at nodes
+    deviation_kinds : HashMap<u32,NfaWordAnalysisDeviationKind>,
+    // explanations of the deviations occurring at nodes
+    explanations : HashMap<u32,NfaWordAnalysisDeviationExplanation>,
+    // suggested completions at terminal nodes
+    completions : HashMap<u32,Vec<Vec<usize>>>,
+    // paths from the initial node to each terminal node, built on termination
+    pub paths : Vec<NfaWordAnalysisPath>
+}
+
+impl NfaWordAnalysisPathsLogger {
+    pub fn new(completions_num : usize) -> Self {
+        Self { completions_num,
+            nodes : HashMap::new(),
+            parents : HashMap::new(),
+            terminals : vec![],
+            deviation_kinds : HashMap::new(),
+            explanations : HashMap::new(),
+            completions : HashMap::new(),
+            paths : vec![] }
+    }
+
+    /// Returns the path which best explains the word, if any.
+    pub fn get_best_path(&self) -> Option<&NfaWordAnalysisPath> {
+        NfaWordAnalysisPath::get_best(&self.paths)
+    }
+
+    fn explain_deviation<Printer : AbstractLanguagePrinter<usize>>(&mut self,
+                                                                   context : &NfaWordAnalysisContext<Printer>,
+                                                                   node_id : u32) {
+        let node = self.nodes.get(&node_id).unwrap();
+        if let Some(explanation) = NfaWordAnalysisDeviationExplanation::from_node(&context.nfa,&context.word,node) {
+            self.explanations.insert(node_id,explanation);
+        }
+    }
+
+    /// Returns the kind of the deviation which the step taken from the node resynchronizes, if any.
+    /// The kind is that of the node unless the step reads the letter swapped with a later one.
+    fn get_deviation_kind(&self,
+                          node_id : u32,
+                          step : &NfaWordAnalysisStepKind) -> Option<NfaWordAnalysisDeviationKind> {
+        match step {
+            NfaWordAnalysisStepKind::ReadNext(_) => {
+                None
+            },
+            NfaWordAnalysisStepKind::ReadSwapped(_,_) => {
+                Some(NfaWordAnalysisDeviationKind::Reordering)
+            },
+            _ => {
+                self.deviation_kinds.get(&node_id).cloned()
+            }
+        }
+    }
+
+    fn make_path(&self,
+                 terminal_id : u32,
+                 verdict : &NfaWordAnalysisLocalVerdict) -> NfaWordAnalysisPath {
+        let mut steps = vec![];
+        let mut deviation_kinds = vec![];
+        let mut explanations = vec![];
+        let mut current_id = terminal_id;
+        if let Some(explanation) = self.explanations.get(&current_id) {
+            explanations.push(explanation.clone());
+        }
+        while let Some((parent_id,step)) = self.parents.get(&current_id) {
+            steps.push((step.clone(),self.nodes.get(&current_id).unwrap().clone()));
+            current_id = *parent_id;
+            if let Some(kind) = self.get_deviation_kind(current_id,step) {
+                deviation_kinds.push(kind);
+            }
+            if let Some(explanation) = self.explanations.get(&current_id) {
+                explanations.push(explanation.clone());
+            }
+        }
+        steps.reverse();
+        deviation_kinds.reverse();
+        explanations.reverse();
+        NfaWordAnalysisPath::new(self.nodes.get(&current_id).unwrap().clone(),
+                                 steps,
+                                 verdict.clone(),
+                                 deviation_kinds,
+                                 explanations,
+                                 self.completions.get(&terminal_id).cloned().unwrap_or_default())
+    }
+}
+
+impl Default for NfaWordAnalysisPathsLogger {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<Printer : AbstractLanguagePrinter<usize> + 'static>
+        AbstractProcessLogger<NfaWordAnalysisConfig<Printer>> for NfaWordAnalysisPathsLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.nodes.clear();
+        self.parents.clear();
+        self.terminals.clear();
+        self.deviation_kinds.clear();
+        self.explanations.clear();
+        self.completions.clear();
+        self.paths.clear();
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<NfaWordAnalysisPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<NfaWordAnalysisFilterCriterion, NfaWordAnalysisFilterEliminationKind>>],
+                            _goal: &Option<NfaWordAnalysisGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &NfaWordAnalysisParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &NfaWordAnalysisContext<Printer>,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &NfaWordAnalysisFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &NfaWordAnalysisContext<Printer>,
+                    _param: &NfaWordAnalysisParameterization,
+                    new_node_id: u32,
+                    new_node: &NfaWordAnalysisNodeKind) {
+        self.nodes.insert(new_node_id,new_node.clone());
+    }
+
+    fn log_new_step(&mut self,
+                    _context: &NfaWordAnalysisContext<Printer>,
+                    _param: &NfaWordAnalysisParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &NfaWordAnalysisStepKind,
+                    _target_node: &NfaWordAnalysisNodeKind,
+                    _target_depth: u32) {
+        // with memoization, a node may be reached several times
+        // only the first way it is reached is kept
+        self.parents.entry(target_node_id).or_insert((origin_node_id,step.clone()));
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               context: &NfaWordAnalysisContext<Printer>,
+                               _param: &NfaWordAnalysisParameterization,
+                               parent_node_id: u32,
+                               verdict: &NfaWordAnalysisLocalVerdict) {
+        self.terminals.push((parent_node_id,verdict.clone()));
+        // the analysis may stop at a deviation
+        self.explain_deviation(context,parent_node_id);
+        if let NfaWordAnalysisLocalVerdict::PrematureEnd = verdict {
+            let node = self.nodes.get(&parent_node_id).unwrap();
+            let completions = get_shortest_completions(&context.nfa,&node.active_states,self.completions_num);
+            self.completions.insert(parent_node_id,completions);
+        }
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      context: &NfaWordAnalysisContext<Printer>,
+                                      _param: &NfaWordAnalysisParameterization,
+                                      parent_node_id: u32,
+                                      verdict: &NfaWordAnalysisLocalVerdict,
+                                      _proof: &NfaWordAnalysisStaticLocalVerdictAnalysisProof) {
+        if let NfaWordAnalysisLocalVerdict::Deviation(kind) = verdict {
+            self.deviation_kinds.insert(parent_node_id,kind.clone());
+        }
+        self.explain_deviation(context,parent_node_id);
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &NfaWordAnalysisGlobalVerdict) {
+        self.paths = self.terminals.iter()
+            .map(|(terminal_id,verdict)| self.make_path(*terminal_id,verdict))
+            .collect();
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &NfaWordAnalysisContext<Printer>,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &NfaWordAnalysisContext<Printer>,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+}
@@ -0,0 +1,149 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
+
+use crate::autana::explanation::NfaWordAnalysisDeviationExplanation;
+use crate::autana::loggers::plog::segment::NfaWordAnalysisSegment;
+use crate::autana::node::NfaWordAnalysisNodeKind;
+use crate::autana::observation::NfaWordAnalysisObservation;
+use crate::autana::step::NfaWordAnalysisStepKind;
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autana::verdict::local::{NfaWordAnalysisDeviationKind, NfaWordAnalysisLocalVerdict};
+
+
+/// A path in the analysis graph, from the initial node to a terminal node.
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub struct NfaWordAnalysisPath {
+    pub init_node : NfaWordAnalysisNodeKind,
+    // each step and the node it leads to
+    pub steps : Vec<(NfaWordAnalysisStepKind,NfaWordAnalysisNodeKind)>,
+    // local verdict at the terminal node
+    pub verdict : NfaWordAnalysisLocalVerdict,
+    // the kind of each deviation along the path, in the order of the resynchronizations
+    pub deviation_kinds : Vec<NfaWordAnalysisDeviationKind>,
+    // the letters which were expected at each deviation along the path
+    pub explanations : Vec<NfaWordAnalysisDeviationExplanation>,
+    // if the trace is emptied without reaching a final state
+    // the shortest suffixes that would have made the word accepted
+    pub completions : Vec<Vec<usize>>
+}
+
+impl NfaWordAnalysisPath {
+    pub fn new(init_node: NfaWordAnalysisNodeKind,
+               steps: Vec<(NfaWordAnalysisStepKind, NfaWordAnalysisNodeKind)>,
+               verdict: NfaWordAnalysisLocalVerdict,
+               deviation_kinds: Vec<NfaWordAnalysisDeviationKind>,
+               explanations: Vec<NfaWordAnalysisDeviationExplanation>,
+               completions: Vec<Vec<usize>>) -> Self {
+        Self { init_node, steps, verdict, deviation_kinds, explanations, completions }
+    }
+
+    pub fn get_last_node(&self) -> &NfaWordAnalysisNodeKind {
+        match self.steps.last() {
+            None => {
+                &self.init_node
+            },
+            Some((_,node)) => {
+                node
+            }
+        }
+    }
+
+    pub fn has_emptied_trace(&self) -> bool {
+        matches!(self.verdict,
+            NfaWordAnalysisLocalVerdict::EmptiedTrace | NfaWordAnalysisLocalVerdict::PrematureEnd)
+    }
+
+    /// Returns the position in the word at which each deviation occurred alongside the step taken to resynchronize.
+    pub fn get_resynchronizations(&self) -> Vec<(usize,&NfaWordAnalysisStepKind)> {
+        let mut resyncs = vec![];
+        let mut pos_in_trace = self.init_node.pos_in_trace;
+        for (step,node) in &self.steps {
+            if let NfaWordAnalysisStepKind::ReadNext(_) = step {
+                // nothing
+            } else {
+                resyncs.push((pos_in_trace,step));
+            }
+            pos_in_trace = node.pos_in_trace;
+        }
+        resyncs
+    }
+
+    /// Splits the word at every reset along the path.
+    /// A letter skipped when resetting belongs to no segment.
+    pub fn get_segments(&self,
+                        nfa : &AutNFAIT<usize>,
+                        word : &[NfaWordAnalysisObservation]) -> Vec<NfaWordAnalysisSegment> {
+        let make_segment = |start : usize, node : &NfaWordAnalysisNodeKind| {
+            NfaWordAnalysisSegment::new(start,
+                                        node.pos_in_trace,
+                                        word[start..node.pos_in_trace].to_vec(),
+                                        node.active_states.iter().any(|st| nfa.finals.contains(st)),
+                                        node.active_states.clone())
+        };
+        let mut segments = vec![];
+        let mut start = self.init_node.pos_in_trace;
+        let mut previous = &self.init_node;
+        for (step,node) in &self.steps {
+            if step.resets() {
+                segments.push(make_segment(start,previous));
+                start = node.pos_in_trace;
+            }
+            previous = node;
+        }
+        segments.push(make_segment(start,previous));
+        segments
+    }
+
+    pub fn get_deviations(&self) -> u32 {
+        self.get_resynchronizations().len() as u32
+    }
+
+    /// Returns the metrics of the explanation of the word along this path only,
+    /// whereas the global verdict of the process sums those of all its branches.
+    pub fn get_verdict(&self) -> NfaWordAnalysisGlobalVerdict {
+        self.deviation_kinds.iter()
+            .fold(NfaWordAnalysisGlobalVerdict::get_baseline_verdict(),
+                  |verdict,kind| verdict.update_with_local_verdict(&NfaWordAnalysisLocalVerdict::Deviation(kind.clone())))
+            .update_with_local_verdict(&self.verdict)
+    }
+
+    /// Paths which empty the trace with fewer deviations, and then which reach a final state or go further in the trace, explain it better.
+    pub fn explains_better_than(&self, other : &Self) -> bool {
+        let key = |path : &Self| (!path.has_emptied_trace(),
+                                  path.get_deviations(),
+                                  path.verdict == NfaWordAnalysisLocalVerdict::PrematureEnd,
+                                  usize::MAX - path.get_last_node().pos_in_trace);
+        key(self) < key(other)
+    }
+
+    pub fn get_best(paths : &[Self]) -> Option<&Self> {
+        let mut best : Option<&Self> = None;
+        for path in paths {
+            match best {
+                Some(got) if !path.explains_better_than(got) => {},
+                _ => {
+                    best = Some(path);
+                }
+            }
+        }
+        best
+    }
+}
@@ -0,0 +1,57 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::autana::observation::NfaWordAnalysisObservation;
+
+
+/// A part of the word read without reset, i.e. a session delimited by the resets of an analysis path.
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub struct NfaWordAnalysisSegment {
+    // interval [start,end[ of the segment in the word
+    pub start : usize,
+    pub end : usize,
+    pub observations : Vec<NfaWordAnalysisObservation>,
+    // whether a final state is active at the end of the segment
+    pub reached_final : bool,
+    // active states at the end of the segment, before the reset
+    pub end_states : BTreeSet<usize>
+}
+
+impl NfaWordAnalysisSegment {
+    pub fn new(start: usize,
+               end: usize,
+               observations: Vec<NfaWordAnalysisObservation>,
+               reached_final: bool,
+               end_states: BTreeSet<usize>) -> Self {
+        Self { start, end, observations, reached_final, end_states }
+    }
+}
+
+impl fmt::Display for NfaWordAnalysisSegment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let states : Vec<String> = self.end_states.iter().map(|st| format!("S{}",st)).collect();
+        write!(f,"[{:},{:}[ ending in {{{}}}{}",
+               self.start,
+               self.end,
+               states.join(","),
+               if self.reached_final { " (final)" } else { "" })
+    }
+}
@@ -27,4 +27,12 @@ pub mod verdict;
 pub mod handling;
 pub mod loggers;
 pub mod param;
-
+pub mod explanation;
+pub mod observation;
+
+pub mod run;
+pub mod window;
+pub mod demux;
+pub mod rank;
+pub mod diff;
+pub mod backward;
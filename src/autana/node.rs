@@ -17,28 +17,65 @@ limitations under the License.
 
 
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashSet};
 use std::hash::Hash;
 
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::letter::AutLetter;
+use autour_core::traits::run::AutRunnable;
+
 use graph_process_manager_core::manager::config::AbstractNodeKind;
 
 
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub struct NfaWordAnalysisNodeKind {
     pub active_states : BTreeSet<usize>,
+    // active states which were only added via the epsilon closure
+    pub closure_states : BTreeSet<usize>,
     pub pos_in_trace : usize
 }
 
 impl NfaWordAnalysisNodeKind {
     pub fn new(active_states: BTreeSet<usize>, pos_in_trace: usize) -> Self {
-        NfaWordAnalysisNodeKind { active_states, pos_in_trace }
+        Self::new_with_closure(active_states, BTreeSet::new(), pos_in_trace)
+    }
+
+    pub fn new_with_closure(active_states: BTreeSet<usize>, closure_states: BTreeSet<usize>, pos_in_trace: usize) -> Self {
+        NfaWordAnalysisNodeKind { active_states, closure_states, pos_in_trace }
+    }
+
+    /// Builds the node in which the active states are the epsilon closure of the reached states.
+    pub fn from_reached_states<Letter : AutLetter>(nfa : &AutNFAIT<Letter>,
+                                                   reached_states : BTreeSet<usize>,
+                                                   pos_in_trace: usize) -> Self {
+        let active_states = get_epsilon_closure(nfa,&reached_states);
+        let closure_states = active_states.difference(&reached_states).cloned().collect();
+        NfaWordAnalysisNodeKind::new_with_closure(active_states,closure_states,pos_in_trace)
     }
 }
 
+/// Same as `AutNFAIT::get_epsilon_closure` on the ordered sets of states the nodes are made of.
+pub fn get_epsilon_closure<Letter : AutLetter>(nfa : &AutNFAIT<Letter>,
+                                               states : &BTreeSet<usize>) -> BTreeSet<usize> {
+    let as_hashset : HashSet<usize> = states.iter().cloned().collect();
+    nfa.get_epsilon_closure(&as_hashset).into_iter().collect()
+}
+
+/// Returns the epsilon closure of the states reached by reading the letter from the given states
+/// (`AutNFAIT::run_transition` does not close the reached states).
+pub fn get_successors<Letter : AutLetter>(nfa : &AutNFAIT<Letter>,
+                                          states : &BTreeSet<usize>,
+                                          letter : &Letter) -> BTreeSet<usize> {
+    let as_hashset : HashSet<usize> = states.iter().cloned().collect();
+    let reached = nfa.run_transition(&as_hashset,letter).unwrap();
+    nfa.get_epsilon_closure(&reached).into_iter().collect()
+}
+
 
 impl AbstractNodeKind for NfaWordAnalysisNodeKind {
     fn is_included_for_memoization(&self, memoized_node: &Self) -> bool {
-        self == memoized_node
+        // how the active states were reached is irrelevant for the rest of the analysis
+        self.active_states == memoized_node.active_states && self.pos_in_trace == memoized_node.pos_in_trace
     }
 }
 
@@ -0,0 +1,82 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashSet};
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+use autour_core::traits::run::AutRunnable;
+
+
+/// What is known of the event at a given position of the word.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaWordAnalysisObservation {
+    Letter(usize),
+    // the event is one of these letters but which one is not known
+    OneOf(BTreeSet<usize>),
+    // any letter of the alphabet of the NFA may have occurred
+    Unknown
+}
+
+impl From<usize> for NfaWordAnalysisObservation {
+    fn from(letter: usize) -> Self {
+        NfaWordAnalysisObservation::Letter(letter)
+    }
+}
+
+impl NfaWordAnalysisObservation {
+    /// Returns the letters which may have occurred.
+    pub fn get_candidates(&self, nfa : &AutNFAIT<usize>) -> BTreeSet<usize> {
+        match self {
+            NfaWordAnalysisObservation::Letter(letter) => {
+                BTreeSet::from([*letter])
+            },
+            NfaWordAnalysisObservation::OneOf(letters) => {
+                letters.clone()
+            },
+            NfaWordAnalysisObservation::Unknown => {
+                nfa.alphabet.iter().cloned().collect()
+            }
+        }
+    }
+
+    /// Returns the states reached by reading any of the candidate letters from the given states.
+    pub fn run_transition(&self,
+                          nfa : &AutNFAIT<usize>,
+                          states : &HashSet<usize>) -> HashSet<usize> {
+        let mut reached = HashSet::new();
+        for letter in self.get_candidates(nfa) {
+            reached.extend(nfa.run_transition(states,&letter).unwrap());
+        }
+        reached
+    }
+
+    pub fn to_string_with_printer<Printer : AbstractLanguagePrinter<usize>>(&self, printer : &Printer) -> String {
+        match self {
+            NfaWordAnalysisObservation::Letter(letter) => {
+                printer.get_letter_string_repr(letter)
+            },
+            NfaWordAnalysisObservation::OneOf(letters) => {
+                let as_strings : Vec<String> = letters.iter().map(|l| printer.get_letter_string_repr(l)).collect();
+                format!("{{{}}}",as_strings.join("|"))
+            },
+            NfaWordAnalysisObservation::Unknown => {
+                "?".to_string()
+            }
+        }
+    }
+}
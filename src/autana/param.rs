@@ -19,22 +19,83 @@ limitations under the License.
 use core::fmt;
 use std::collections::{BTreeSet, HashSet};
 use std::fmt::Formatter;
+use maplit::{btreeset,hashset};
 
-use autour_core::nfa::nfa::AutNFA;
+use autour_core::nfait::nfait::AutNFAIT;
 use autour_core::traits::letter::AutLetter;
 
 use graph_process_manager_core::manager::config::AbstractProcessParameterization;
-use crate::autana::node::NfaWordAnalysisNodeKind;
+use crate::autana::node::{get_epsilon_closure, get_successors, NfaWordAnalysisNodeKind};
 
 #[derive(Debug, Clone)]
 pub enum NfaWordAnalysisResetOn {
     Initials,
     AllStates,
-    Specific(HashSet<usize>)
+    Specific(HashSet<usize>),
+    // ***
+    // the states from which the next k letters of the word can be read
+    // (with k=1 these are the states having an outgoing transition on the offending letter)
+    NextLettersEnabled(usize),
+    // ***
+    // the states reached by reading the last k letters preceding the deviation from any state
+    LastLettersConsistent(usize)
 }
 
 impl NfaWordAnalysisResetOn {
-    pub fn get_reset_states<Letter : AutLetter>(&self, nfa : &AutNFA<Letter>) -> BTreeSet<usize> {
+    /// Returns the states on which to reset,
+    /// knowing the letters of the word that precede the deviation
+    /// and those that remain to be read after the reset.
+    pub fn get_reset_states<Letter : AutLetter>(&self,
+                                                nfa : &AutNFAIT<Letter>,
+                                                history : &[Letter],
+                                                upcoming : &[Letter]) -> BTreeSet<usize> {
+        let as_candidates = |letters : &[Letter]| -> Vec<BTreeSet<Letter>> {
+            letters.iter().map(|letter| btreeset!{*letter}).collect()
+        };
+        let (history,upcoming) = self.get_lookaround(history,upcoming);
+        self.get_reset_states_on_candidates(nfa,&as_candidates(history),&as_candidates(upcoming))
+    }
+
+    /// Returns the number of letters preceding the deviation
+    /// and the number of letters following the reset on which the reset states depend.
+    pub fn get_lookaround_lengths(&self) -> (usize,usize) {
+        match self {
+            NfaWordAnalysisResetOn::Initials |
+            NfaWordAnalysisResetOn::AllStates |
+            NfaWordAnalysisResetOn::Specific(_) => {
+                (0,0)
+            },
+            NfaWordAnalysisResetOn::NextLettersEnabled(k) => {
+                (0,*k)
+            },
+            NfaWordAnalysisResetOn::LastLettersConsistent(k) => {
+                (*k,0)
+            }
+        }
+    }
+
+    /// Returns the end of the history and the start of the upcoming word on which the reset states depend.
+    pub fn get_lookaround<'a,T>(&self,
+                                history : &'a [T],
+                                upcoming : &'a [T]) -> (&'a [T],&'a [T]) {
+        let (lookbehind,lookahead) = self.get_lookaround_lengths();
+        (&history[history.len().saturating_sub(lookbehind)..],
+         &upcoming[..lookahead.min(upcoming.len())])
+    }
+
+    /// Same as above when each position of the word may be one of several letters.
+    /// Lookahead and lookbehind consider that any of the candidate letters may have occurred.
+    pub fn get_reset_states_on_candidates<Letter : AutLetter>(&self,
+                                                              nfa : &AutNFAIT<Letter>,
+                                                              history : &[BTreeSet<Letter>],
+                                                              upcoming : &[BTreeSet<Letter>]) -> BTreeSet<usize> {
+        let get_successors_on_any = |states : &BTreeSet<usize>, candidates : &BTreeSet<Letter>| {
+            let mut reached = BTreeSet::new();
+            for letter in candidates {
+                reached.extend(get_successors(nfa,states,letter));
+            }
+            reached
+        };
         match self {
             NfaWordAnalysisResetOn::Initials => {
                 nfa.initials.iter().cloned().collect()
@@ -44,11 +105,41 @@ impl NfaWordAnalysisResetOn {
             },
             NfaWordAnalysisResetOn::Specific(ref reset_states) => {
                 reset_states.iter().cloned().collect()
+            },
+            NfaWordAnalysisResetOn::NextLettersEnabled(k) => {
+                let lookahead = &upcoming[..(*k).min(upcoming.len())];
+                (0..nfa.transitions.len()).filter(|state| {
+                    let mut current = get_epsilon_closure(nfa,&btreeset!{*state});
+                    for candidates in lookahead {
+                        current = get_successors_on_any(&current,candidates);
+                        if current.is_empty() {
+                            return false;
+                        }
+                    }
+                    true
+                }).collect()
+            },
+            NfaWordAnalysisResetOn::LastLettersConsistent(k) => {
+                let lookbehind = &history[history.len().saturating_sub(*k)..];
+                let mut current = get_epsilon_closure(nfa,&(0..nfa.transitions.len()).collect());
+                for candidates in lookbehind {
+                    current = get_successors_on_any(&current,candidates);
+                }
+                current
             }
         }
     }
 }
 
+impl NfaWordAnalysisResetOn {
+    /// Returns one reset alternative per initial state of the NFA.
+    pub fn each_initial<Letter : AutLetter>(nfa : &AutNFAIT<Letter>) -> Vec<NfaWordAnalysisResetOn> {
+        let mut initials : Vec<usize> = nfa.initials.iter().cloned().collect();
+        initials.sort();
+        initials.into_iter().map(|st| NfaWordAnalysisResetOn::Specific(hashset!{st})).collect()
+    }
+}
+
 impl fmt::Display for NfaWordAnalysisResetOn {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -60,6 +151,26 @@ impl fmt::Display for NfaWordAnalysisResetOn {
             },
             NfaWordAnalysisResetOn::Specific(states) => {
                 write!(f,"on specific states : {:?}", states)
+            },
+            NfaWordAnalysisResetOn::NextLettersEnabled(k) => {
+                match k {
+                    1 => {
+                        write!(f,"on states enabling the next letter")
+                    },
+                    x => {
+                        write!(f,"on states enabling the next {:} letters", x)
+                    }
+                }
+            },
+            NfaWordAnalysisResetOn::LastLettersConsistent(k) => {
+                match k {
+                    1 => {
+                        write!(f,"on states consistent with the last letter")
+                    },
+                    x => {
+                        write!(f,"on states consistent with the last {:} letters", x)
+                    }
+                }
             }
         }
     }
@@ -69,7 +180,11 @@ impl fmt::Display for NfaWordAnalysisResetOn {
 pub enum NfaWordAnalysisPolicy {
     StopAtDeviation,
     TryResetThenMaySkip(NfaWordAnalysisResetOn,bool),
-    SkipAndMayReset(Option<NfaWordAnalysisResetOn>)
+    SkipAndMayReset(Option<NfaWordAnalysisResetOn>),
+    // ***
+    // explores each reset alternative which allows reading the letter as a separate branch
+    // and if none does, may skip the letter (branching over the resets as well)
+    BranchOnResets(Vec<NfaWordAnalysisResetOn>,bool)
 }
 
 impl fmt::Display for NfaWordAnalysisPolicy {
@@ -94,6 +209,15 @@ impl fmt::Display for NfaWordAnalysisPolicy {
                         write!(f,"skip letter and reset {:}",reset)
                     }
                 }
+            },
+            NfaWordAnalysisPolicy::BranchOnResets(alternatives,skip) => {
+                let as_strings : Vec<String> = alternatives.iter().enumerate()
+                    .map(|(id,reset)| format!("#{:} {:}",id,reset)).collect();
+                if *skip {
+                    write!(f,"branch over resets [{:}] and if all fail then skip letter",as_strings.join(", "))
+                } else {
+                    write!(f,"branch over resets [{:}]",as_strings.join(", "))
+                }
             }
         }
     }
@@ -115,6 +239,20 @@ impl NfaWordAnalysisPolicy {
                 } else {
                     None
                 }
+            },
+            NfaWordAnalysisPolicy::BranchOnResets(_,_) => {
+                None
+            }
+        }
+    }
+
+    pub fn get_reset_alternative(&self, alternative : usize) -> Option<&NfaWordAnalysisResetOn> {
+        match self {
+            NfaWordAnalysisPolicy::BranchOnResets(alternatives,_) => {
+                alternatives.get(alternative)
+            },
+            _ => {
+                None
             }
         }
     }
@@ -123,15 +261,27 @@ impl NfaWordAnalysisPolicy {
 #[derive(Debug, Clone)]
 pub struct NfaWordAnalysisParameterization {
     pub start_on : NfaWordAnalysisResetOn,
-    pub policy : NfaWordAnalysisPolicy
+    pub policy : NfaWordAnalysisPolicy,
+    // on deviation, the offending letter may be swapped with a letter up to this distance further in the word
+    // alongside the steps of the policy, unless the analysis stops at deviations
+    // (0 means no reordering is tolerated)
+    pub max_swap_distance : usize
 }
 
 impl NfaWordAnalysisParameterization {
     pub fn new(start_on: NfaWordAnalysisResetOn, policy: NfaWordAnalysisPolicy) -> Self {
-        Self { start_on, policy }
+        Self { start_on, policy, max_swap_distance : 0 }
     }
-    pub fn make_init_node<Letter : AutLetter>(&self, nfa : &AutNFA<Letter>) -> NfaWordAnalysisNodeKind {
-        NfaWordAnalysisNodeKind::new(self.start_on.get_reset_states(nfa),0)
+
+    pub fn with_max_swap_distance(mut self, max_swap_distance : usize) -> Self {
+        self.max_swap_distance = max_swap_distance;
+        self
+    }
+
+    pub fn make_init_node<Letter : AutLetter>(&self,
+                                              nfa : &AutNFAIT<Letter>,
+                                              word : &[Letter]) -> NfaWordAnalysisNodeKind {
+        NfaWordAnalysisNodeKind::from_reached_states(nfa,self.start_on.get_reset_states(nfa,&[],word),0)
     }
 }
 
@@ -141,6 +291,9 @@ impl AbstractProcessParameterization for NfaWordAnalysisParameterization {
         let mut params = vec!["process = NFA word analysis".to_string()];
         params.push( format!("start = {:}", self.start_on));
         params.push( format!("on deviation policy = {:}", self.policy));
+        if self.max_swap_distance > 0 {
+            params.push( format!("max swap distance = {:}", self.max_swap_distance));
+        }
         params
     }
 }
@@ -22,18 +22,60 @@ use graph_process_manager_core::delegate::priorities::AbstractPriorities;
 use crate::autana::step::NfaWordAnalysisStepKind;
 
 
-pub struct NfaWordAnalysisPriorities {}
+pub struct NfaWordAnalysisPriorities {
+    pub read : i32,
+    pub reset : i32,
+    pub skip : i32
+}
+
+impl NfaWordAnalysisPriorities {
+    pub fn new(read: i32, reset: i32, skip: i32) -> Self {
+        Self { read, reset, skip }
+    }
+}
+
+impl Default for NfaWordAnalysisPriorities {
+    fn default() -> Self {
+        NfaWordAnalysisPriorities::new(0,0,0)
+    }
+}
 
 
 impl fmt::Display for NfaWordAnalysisPriorities {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f,"")
+        write!(f,"[read={:},reset={:},skip={:}]",
+               self.read,
+               self.reset,
+               self.skip)
     }
 }
 
 impl AbstractPriorities<NfaWordAnalysisStepKind> for NfaWordAnalysisPriorities {
-    fn get_priority_of_step(&self, _step: &NfaWordAnalysisStepKind) -> i32 {
-        0
+    fn get_priority_of_step(&self, step: &NfaWordAnalysisStepKind) -> i32 {
+        match step {
+            NfaWordAnalysisStepKind::ReadNext(_) => {
+                self.read
+            },
+            NfaWordAnalysisStepKind::ResetAndOrSkip(reset,skip) => {
+                let mut priority = 0;
+                if *reset {
+                    priority += self.reset;
+                }
+                if *skip {
+                    priority += self.skip;
+                }
+                priority
+            },
+            NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(_,skip) => {
+                if *skip {
+                    self.reset + self.skip
+                } else {
+                    self.reset
+                }
+            },
+            NfaWordAnalysisStepKind::ReadSwapped(_,_) => {
+                self.read
+            }
+        }
     }
 }
-
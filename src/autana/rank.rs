@@ -0,0 +1,109 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::run::{analyze_word, get_path_verdict};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+
+
+/// The analysis of a word against one of several candidate specifications.
+pub struct NfaWordAnalysisRanking {
+    // index of the specification in the list of candidates
+    pub spec_index : usize,
+    // metrics of the best explanation of the word by the specification
+    pub verdict : NfaWordAnalysisGlobalVerdict,
+    // number of letters read along the best path before its first deviation
+    pub reenacted_prefix : usize
+}
+
+impl NfaWordAnalysisRanking {
+    pub fn new(spec_index: usize, verdict: NfaWordAnalysisGlobalVerdict, reenacted_prefix: usize) -> Self {
+        Self { spec_index, verdict, reenacted_prefix }
+    }
+
+    /// Ranks the specification from the path which best explains the word,
+    /// and not from the global verdict of the analysis, which sums the deviations of all its branches.
+    pub fn from_best_path(spec_index : usize, best_path : &Option<NfaWordAnalysisPath>) -> Self {
+        Self::new(spec_index,get_path_verdict(best_path),get_reenacted_prefix(best_path))
+    }
+
+    /// Specifications which empty the word with fewer deviations, then with fewer premature ends,
+    /// and then with a longer re-enacted prefix, explain the word better
+    /// (as for the comparison of paths within a single analysis).
+    pub fn explains_better_than(&self, other : &Self) -> bool {
+        self.get_key() < other.get_key()
+    }
+
+    fn get_key(&self) -> (bool,u32,u32,usize) {
+        (!self.verdict.emptied_trace,
+         self.verdict.deviations,
+         self.verdict.premature_ends,
+         usize::MAX - self.reenacted_prefix)
+    }
+}
+
+impl fmt::Display for NfaWordAnalysisRanking {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,"spec #{:} : {:} (re-enacted prefix of {:} letters)",
+               self.spec_index,
+               self.verdict,
+               self.reenacted_prefix)
+    }
+}
+
+fn get_reenacted_prefix(path : &Option<NfaWordAnalysisPath>) -> usize {
+    match path {
+        None => {
+            0
+        },
+        Some(path) => {
+            match path.get_resynchronizations().first() {
+                None => {
+                    path.get_last_node().pos_in_trace
+                },
+                Some((pos_in_trace,_)) => {
+                    *pos_in_trace
+                }
+            }
+        }
+    }
+}
+
+/// Analyzes the word against each specification with the same parameterization
+/// and returns the results from the specification which best explains the word to the one which explains it the least.
+/// Specifications which explain the word equally well keep their relative order,
+/// hence the first result designates the specification which best explains the word.
+pub fn rank_specifications<Printer : AbstractLanguagePrinter<usize> + Clone + 'static>(specs : &[AutNFAIT<usize>],
+                                                                                       printer : &Printer,
+                                                                                       word : &[usize],
+                                                                                       param : &NfaWordAnalysisParameterization)
+            -> Vec<NfaWordAnalysisRanking> {
+    let mut rankings : Vec<NfaWordAnalysisRanking> = specs.iter().enumerate()
+        .map(|(spec_index,nfa)| {
+            let (_,best_path) = analyze_word(nfa,printer.clone(),word.to_vec(),param);
+            NfaWordAnalysisRanking::from_best_path(spec_index,&best_path)
+        }).collect();
+    rankings.sort_by_key(|ranking| ranking.get_key());
+    rankings
+}
@@ -0,0 +1,71 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::autana::conf::NfaWordAnalysisConfig;
+use crate::autana::context::NfaWordAnalysisContext;
+use crate::autana::loggers::plog::logger::NfaWordAnalysisPathsLogger;
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::priorities::NfaWordAnalysisPriorities;
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+
+
+/// Runs the analysis of a word without drawing it and returns the best explanation path alongside its metrics.
+/// These are not the global verdict of the process, which sums the deviations of all the branches.
+pub fn analyze_word<Printer : AbstractLanguagePrinter<usize> + 'static>(nfa : &AutNFAIT<usize>,
+                                                                         printer : Printer,
+                                                                         word : Vec<usize>,
+                                                                         param : &NfaWordAnalysisParameterization)
+            -> (NfaWordAnalysisGlobalVerdict,Option<NfaWordAnalysisPath>) {
+    let init_node = param.make_init_node(nfa,&word);
+    let process_ctx = NfaWordAnalysisContext::from_nfait(nfa.clone(),printer,word);
+    let priorities = GenericProcessPriorities::new(NfaWordAnalysisPriorities::default(),false);
+    let delegate = GenericProcessDelegate::new(QueueSearchStrategy::BFS,priorities);
+    let mut manager : GenericProcessManager<NfaWordAnalysisConfig<Printer>> = GenericProcessManager::new(process_ctx,
+                                                                                                          param.clone(),
+                                                                                                          delegate,
+                                                                                                          vec![],
+                                                                                                          vec![Box::new(NfaWordAnalysisPathsLogger::default())],
+                                                                                                          None,
+                                                                                                          false);
+    manager.start_process(init_node);
+    let paths_logger = manager.get_logger(0).unwrap()
+        .as_any().downcast_ref::<NfaWordAnalysisPathsLogger>().unwrap();
+    let best_path = paths_logger.get_best_path().cloned();
+    (get_path_verdict(&best_path),best_path)
+}
+
+/// The metrics of the path, or those of an analysis which did not empty the trace if there is none.
+pub fn get_path_verdict(path : &Option<NfaWordAnalysisPath>) -> NfaWordAnalysisGlobalVerdict {
+    match path {
+        None => {
+            NfaWordAnalysisGlobalVerdict::get_baseline_verdict()
+        },
+        Some(path) => {
+            path.get_verdict()
+        }
+    }
+}
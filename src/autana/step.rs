@@ -19,7 +19,10 @@ use std::collections::BTreeSet;
 use std::fmt;
 use std::fmt::Formatter;
 
+use crate::autana::handling::policy::NfaWordAnalysisPolicyStep;
 
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum NfaWordAnalysisStepKind {
     // ***
     // read the next letter in the word and go to next set of active states
@@ -29,7 +32,66 @@ pub enum NfaWordAnalysisStepKind {
     // on deviation may reset active states and/or skip the next letter in the word
     // first arg if reset
     // second arg if skip
-    ResetAndOrSkip(bool,bool)
+    ResetAndOrSkip(bool,bool),
+    // ***
+    // on deviation, when the policy branches over several reset alternatives
+    // first arg is the index of the chosen reset alternative
+    // second arg if skip
+    ResetOnAlternativeAndOrSkip(usize,bool),
+    // ***
+    // on deviation, read the letter at the given distance first and the offending letter last
+    // i.e. the two letters were swapped in the word
+    // contains the new set of active states once the letters in between are read as well
+    ReadSwapped(usize,BTreeSet<usize>)
+}
+
+impl From<NfaWordAnalysisPolicyStep> for NfaWordAnalysisStepKind {
+    fn from(step : NfaWordAnalysisPolicyStep) -> Self {
+        match step {
+            NfaWordAnalysisPolicyStep::ResetAndOrSkip(reset,skip) => {
+                NfaWordAnalysisStepKind::ResetAndOrSkip(reset,skip)
+            },
+            NfaWordAnalysisPolicyStep::ResetOnAlternativeAndOrSkip(alternative,skip) => {
+                NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(alternative,skip)
+            }
+        }
+    }
+}
+
+impl NfaWordAnalysisStepKind {
+    pub fn skips_letter(&self) -> bool {
+        match self {
+            NfaWordAnalysisStepKind::ReadNext(_) => {
+                false
+            },
+            NfaWordAnalysisStepKind::ResetAndOrSkip(_,skip) => {
+                *skip
+            },
+            NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(_,skip) => {
+                *skip
+            },
+            NfaWordAnalysisStepKind::ReadSwapped(_,_) => {
+                false
+            }
+        }
+    }
+
+    pub fn resets(&self) -> bool {
+        match self {
+            NfaWordAnalysisStepKind::ReadNext(_) => {
+                false
+            },
+            NfaWordAnalysisStepKind::ResetAndOrSkip(reset,_) => {
+                *reset
+            },
+            NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(_,_) => {
+                true
+            },
+            NfaWordAnalysisStepKind::ReadSwapped(_,_) => {
+                false
+            }
+        }
+    }
 }
 
 impl fmt::Display for NfaWordAnalysisStepKind {
@@ -53,6 +115,16 @@ impl fmt::Display for NfaWordAnalysisStepKind {
                         panic!()
                     }
                 }
+            },
+            NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(alternative,skip) => {
+                if *skip {
+                    write!(f,"skip and reset #{:}",alternative)
+                } else {
+                    write!(f,"reset #{:}",alternative)
+                }
+            },
+            NfaWordAnalysisStepKind::ReadSwapped(distance,_) => {
+                write!(f,"read swapped at distance {:}",distance)
             }
         }
     }
@@ -18,46 +18,100 @@ limitations under the License.
 use std::fmt;
 use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
 
-use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
+use crate::autana::verdict::local::{NfaWordAnalysisDeviationKind, NfaWordAnalysisLocalVerdict};
 
 
 
+/// Metrics of the analysis of a word.
+/// As the global verdict of the process, the local verdicts of all the branches are summed,
+/// hence, when the policy branches, the metrics of the best explanation are those of the best path
+/// (see `NfaWordAnalysisPath::get_verdict`).
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct NfaWordAnalysisGlobalVerdict{
     pub deviations : u32,
-    pub emptied_trace : bool
+    pub emptied_trace : bool,
+    // ***
+    // number of deviations per category
+    pub unexpected_letters : u32,
+    pub unknown_letters : u32,
+    pub ineffective_resets : u32,
+    pub reorderings : u32,
+    // ***
+    // number of times the trace was emptied without reaching a final state
+    pub premature_ends : u32
 }
 
 impl NfaWordAnalysisGlobalVerdict {
     pub fn new(deviations: u32, emptied_trace: bool) -> Self {
-        Self { deviations, emptied_trace }
+        Self { deviations,
+            emptied_trace,
+            unexpected_letters : 0,
+            unknown_letters : 0,
+            ineffective_resets : 0,
+            reorderings : 0,
+            premature_ends : 0 }
     }
-}
 
-impl fmt::Display for NfaWordAnalysisGlobalVerdict {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn get_details(&self) -> Vec<String> {
+        let mut details = vec![];
+        for (count,singular,plural) in [(self.unexpected_letters,"unexpected letter","unexpected letters"),
+                                        (self.unknown_letters,"unknown letter","unknown letters"),
+                                        (self.ineffective_resets,"ineffective reset","ineffective resets"),
+                                        (self.reorderings,"reordering","reorderings"),
+                                        (self.premature_ends,"premature end","premature ends")] {
+            match count {
+                0 => {},
+                1 => {
+                    details.push(format!("1 {:}",singular));
+                },
+                x => {
+                    details.push(format!("{:} {:}",x,plural));
+                }
+            }
+        }
+        details
+    }
+
+    /// Prints the verdict with the number of deviations per category,
+    /// followed by the given details about other categories.
+    pub(crate) fn fmt_with_details(&self,
+                                   f: &mut fmt::Formatter<'_>,
+                                   other_details : Vec<String>) -> fmt::Result {
         if self.emptied_trace {
             match self.deviations {
                 0 => {
-                    write!(f,"re-enacted trace without deviations")
+                    write!(f,"re-enacted trace without deviations")?;
                 },
                 1 => {
-                    write!(f,"re-enacted trace with 1 deviation")
+                    write!(f,"re-enacted trace with 1 deviation")?;
                 },
                 x => {
-                    write!(f,"re-enacted trace with {:} deviations", x)
+                    write!(f,"re-enacted trace with {:} deviations", x)?;
                 }
             }
         } else {
             match self.deviations {
                 0 => {
-                    write!(f,"failed to re-enact trace")
+                    write!(f,"failed to re-enact trace")?;
                 },
                 x => {
-                    write!(f,"failed to re-enact trace ({:} deviations)", x)
+                    write!(f,"failed to re-enact trace ({:} deviations)", x)?;
                 }
             }
         }
+        let mut details = self.get_details();
+        details.extend(other_details);
+        if details.is_empty() {
+            Ok(())
+        } else {
+            write!(f," [{:}]", details.join(", "))
+        }
+    }
+}
+
+impl fmt::Display for NfaWordAnalysisGlobalVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_details(f,vec![])
     }
 }
 
@@ -75,13 +129,30 @@ impl AbstractGlobalVerdict<NfaWordAnalysisLocalVerdict> for NfaWordAnalysisGloba
                                  local_verdict: &NfaWordAnalysisLocalVerdict) -> Self {
         match local_verdict {
             NfaWordAnalysisLocalVerdict::EmptiedTrace => {
-                NfaWordAnalysisGlobalVerdict::new(self.deviations,true)
+                NfaWordAnalysisGlobalVerdict{emptied_trace:true,..self}
+            },
+            NfaWordAnalysisLocalVerdict::PrematureEnd => {
+                NfaWordAnalysisGlobalVerdict{emptied_trace:true,premature_ends:self.premature_ends + 1,..self}
             },
             NfaWordAnalysisLocalVerdict::FailureToEmptyTrace => {
-                NfaWordAnalysisGlobalVerdict::new(self.deviations,false)
+                NfaWordAnalysisGlobalVerdict{emptied_trace:false,..self}
             },
-            NfaWordAnalysisLocalVerdict::Deviation => {
-                NfaWordAnalysisGlobalVerdict::new(self.deviations + 1,self.emptied_trace)
+            NfaWordAnalysisLocalVerdict::Deviation(kind) => {
+                let updated = NfaWordAnalysisGlobalVerdict{deviations:self.deviations + 1,..self};
+                match kind {
+                    NfaWordAnalysisDeviationKind::UnexpectedLetter => {
+                        NfaWordAnalysisGlobalVerdict{unexpected_letters:updated.unexpected_letters + 1,..updated}
+                    },
+                    NfaWordAnalysisDeviationKind::UnknownLetter => {
+                        NfaWordAnalysisGlobalVerdict{unknown_letters:updated.unknown_letters + 1,..updated}
+                    },
+                    NfaWordAnalysisDeviationKind::ResetIneffective => {
+                        NfaWordAnalysisGlobalVerdict{ineffective_resets:updated.ineffective_resets + 1,..updated}
+                    },
+                    NfaWordAnalysisDeviationKind::Reordering => {
+                        NfaWordAnalysisGlobalVerdict{reorderings:updated.reorderings + 1,..updated}
+                    }
+                }
             }
         }
     }
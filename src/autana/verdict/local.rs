@@ -18,21 +18,58 @@ limitations under the License.
 
 use std::fmt;
 
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaWordAnalysisDeviationKind {
+    // the letter labels transitions of the NFA but none from the active states
+    UnexpectedLetter,
+    // no transition of the NFA is labelled by the letter
+    UnknownLetter,
+    // resetting the NFA did not allow reading the letter
+    ResetIneffective,
+    // the letter could only be read after a letter which follows it in the word
+    Reordering
+}
+
+impl fmt::Display for NfaWordAnalysisDeviationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaWordAnalysisDeviationKind::UnexpectedLetter => {
+                write!(f,"UnexpectedLetter")
+            },
+            NfaWordAnalysisDeviationKind::UnknownLetter => {
+                write!(f,"UnknownLetter")
+            },
+            NfaWordAnalysisDeviationKind::ResetIneffective => {
+                write!(f,"ResetIneffective")
+            },
+            NfaWordAnalysisDeviationKind::Reordering => {
+                write!(f,"Reordering")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum NfaWordAnalysisLocalVerdict{
-    Deviation,
+    Deviation(NfaWordAnalysisDeviationKind),
     FailureToEmptyTrace,
+    // the trace is emptied but no active state is final
+    PrematureEnd,
     EmptiedTrace
 }
 
 impl fmt::Display for NfaWordAnalysisLocalVerdict {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            NfaWordAnalysisLocalVerdict::Deviation => {
-                write!(f,"Deviation")
+            NfaWordAnalysisLocalVerdict::Deviation(kind) => {
+                write!(f,"Deviation({:})",kind)
             },
             NfaWordAnalysisLocalVerdict::FailureToEmptyTrace => {
                 write!(f,"FailureToEmptyTrace")
             },
+            NfaWordAnalysisLocalVerdict::PrematureEnd => {
+                write!(f,"PrematureEnd")
+            },
             NfaWordAnalysisLocalVerdict::EmptiedTrace => {
                 write!(f,"EmptiedTrace")
             }
@@ -40,4 +77,3 @@ impl fmt::Display for NfaWordAnalysisLocalVerdict {
     }
 }
 
-
@@ -0,0 +1,119 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::run::{analyze_word, get_path_verdict};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+
+
+/// How a long word is cut into windows of n letters which are analyzed independently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NfaWordAnalysisWindowing {
+    // consecutive windows which do not overlap
+    Tumbling(usize),
+    // a window starts at every position of the word
+    Sliding(usize)
+}
+
+impl NfaWordAnalysisWindowing {
+    /// Returns the intervals [start,end[ of the windows.
+    /// The last windows may be shorter than n if the word is not long enough.
+    /// An empty word has no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the windows is 0.
+    pub fn get_windows(&self, word_len : usize) -> Vec<(usize,usize)> {
+        match self {
+            NfaWordAnalysisWindowing::Tumbling(size) => {
+                assert!(*size > 0, "windows must contain at least one letter");
+                (0..word_len).step_by(*size)
+                    .map(|start| (start,(start + size).min(word_len)))
+                    .collect()
+            },
+            NfaWordAnalysisWindowing::Sliding(size) => {
+                assert!(*size > 0, "windows must contain at least one letter");
+                if word_len == 0 {
+                    vec![]
+                } else if word_len <= *size {
+                    vec![(0,word_len)]
+                } else {
+                    (0..=(word_len - size))
+                        .map(|start| (start,start + size))
+                        .collect()
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for NfaWordAnalysisWindowing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaWordAnalysisWindowing::Tumbling(size) => {
+                write!(f,"tumbling windows of {:} letters",size)
+            },
+            NfaWordAnalysisWindowing::Sliding(size) => {
+                write!(f,"sliding windows of {:} letters",size)
+            }
+        }
+    }
+}
+
+pub struct NfaWordAnalysisWindowVerdict {
+    pub start : usize,
+    pub end : usize,
+    // metrics of the path which best explains the window
+    pub verdict : NfaWordAnalysisGlobalVerdict
+}
+
+impl NfaWordAnalysisWindowVerdict {
+    pub fn new(start: usize, end: usize, verdict: NfaWordAnalysisGlobalVerdict) -> Self {
+        Self { start, end, verdict }
+    }
+}
+
+impl fmt::Display for NfaWordAnalysisWindowVerdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,"[{:},{:}[ : {:}",self.start,self.end,self.verdict)
+    }
+}
+
+/// Analyzes every window of the word from the start_on states of the parameterization.
+pub fn analyze_windows<Printer : AbstractLanguagePrinter<usize> + Clone + 'static>(nfa : &AutNFAIT<usize>,
+                                                                                   printer : &Printer,
+                                                                                   word : &[usize],
+                                                                                   param : &NfaWordAnalysisParameterization,
+                                                                                   windowing : &NfaWordAnalysisWindowing)
+            -> Vec<NfaWordAnalysisWindowVerdict> {
+    windowing.get_windows(word.len()).into_iter()
+        .map(|(start,end)| {
+            let (_,best_path) = analyze_word(nfa,printer.clone(),word[start..end].to_vec(),param);
+            NfaWordAnalysisWindowVerdict::new(start,end,get_path_verdict(&best_path))
+        }).collect()
+}
+
+/// Returns the number of deviations along the best path of each window, in the order of the windows.
+pub fn get_deviations_timeline(windows : &[NfaWordAnalysisWindowVerdict]) -> Vec<u32> {
+    windows.iter().map(|window| window.verdict.deviations).collect()
+}
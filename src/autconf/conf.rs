@@ -0,0 +1,56 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+
+use crate::autana::filter::elim::NfaWordAnalysisFilterEliminationKind;
+use crate::autana::filter::filter::NfaWordAnalysisFilterCriterion;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::priorities::NfaWordAnalysisPriorities;
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::handling::handler::NfaConfigurationAnalysisProcessHandler;
+use crate::autconf::node::NfaConfigurationAnalysisNodeKind;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autconf::step::NfaConfigurationAnalysisStepKind;
+use crate::autconf::verdict::global::NfaConfigurationAnalysisGlobalVerdict;
+use crate::autconf::verdict::local::NfaConfigurationAnalysisLocalVerdict;
+
+
+
+pub struct NfaConfigurationAnalysisConfig<Semantics : NfaConfigurationSemantics,
+                                          Printer : AbstractLanguagePrinter<usize>> {
+    phantom : std::marker::PhantomData<(Semantics,Printer)>
+}
+
+pub struct NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof{}
+
+impl<Semantics : NfaConfigurationSemantics,
+     Printer : AbstractLanguagePrinter<usize>> AbstractProcessConfiguration for NfaConfigurationAnalysisConfig<Semantics,Printer> {
+    type Context = NfaConfigurationAnalysisContext<Semantics,Printer>;
+    type Parameterization = NfaWordAnalysisParameterization;
+    type NodeKind = NfaConfigurationAnalysisNodeKind<Semantics::Configurations>;
+    type StepKind = NfaConfigurationAnalysisStepKind<Semantics::Configurations>;
+    type Priorities = NfaWordAnalysisPriorities;
+    type FilterCriterion = NfaWordAnalysisFilterCriterion;
+    type FilterEliminationKind = NfaWordAnalysisFilterEliminationKind;
+    type LocalVerdict = NfaConfigurationAnalysisLocalVerdict<Semantics::Violation>;
+    type StaticLocalVerdictAnalysisProof = NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof;
+    type GlobalVerdict = NfaConfigurationAnalysisGlobalVerdict<Semantics::Violation>;
+    type ProcessHandler = NfaConfigurationAnalysisProcessHandler;
+}
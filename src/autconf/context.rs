@@ -0,0 +1,62 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisResetOn};
+use crate::autconf::node::NfaConfigurationAnalysisNodeKind;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+
+
+pub struct NfaConfigurationAnalysisContext<Semantics : NfaConfigurationSemantics,
+                                           Printer : AbstractLanguagePrinter<usize>> {
+    pub semantics : Semantics,
+    pub printer : Printer,
+    pub word : Vec<Semantics::Event>
+}
+
+impl<Semantics : NfaConfigurationSemantics,
+     Printer : AbstractLanguagePrinter<usize>> NfaConfigurationAnalysisContext<Semantics,Printer> {
+    pub fn new(semantics: Semantics, printer: Printer, word: Vec<Semantics::Event>) -> Self {
+        Self { semantics, printer, word }
+    }
+
+    /// Configurations on which to reset knowing the events preceding the deviation
+    /// and those from which the word is to be read after the reset.
+    pub fn get_reset_configurations(&self,
+                                    reset : &NfaWordAnalysisResetOn,
+                                    history_len : usize,
+                                    upcoming_from : usize) -> Semantics::Configurations {
+        self.semantics.get_reset_configurations(reset,&self.word[..history_len],&self.word[upcoming_from..])
+    }
+
+    pub fn read_event(&self,
+                      configurations : &Semantics::Configurations,
+                      pos_in_trace : usize) -> Result<Semantics::Configurations,Option<Semantics::Violation>> {
+        self.semantics.read_event(configurations,&self.word,pos_in_trace)
+    }
+
+    /// Events carry data which depends on their order in the word (e.g. timestamps) hence they are never swapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameterization tolerates swaps.
+    pub fn make_init_node(&self, param : &NfaWordAnalysisParameterization)
+                -> NfaConfigurationAnalysisNodeKind<Semantics::Configurations> {
+        assert_eq!(param.max_swap_distance, 0, "events cannot be swapped in the analysis of configurations");
+        NfaConfigurationAnalysisNodeKind::new(self.get_reset_configurations(&param.start_on,0,0),0)
+    }
+}
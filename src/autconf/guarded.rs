@@ -0,0 +1,83 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::param::NfaWordAnalysisResetOn;
+
+
+/// A transition given by its origin state, its letter and its target state.
+pub type NfaGuardedTransition = (usize,usize,usize);
+
+/// An active state alongside the data which the run reaching it has accumulated.
+pub type NfaGuardedConfiguration<Data> = (usize,Data);
+
+/// Epsilon transitions carry neither guards nor updates of the data.
+pub fn get_guarded_epsilon_closure<Data : Clone + Ord>(nfa : &AutNFAIT<usize>,
+                                                       configurations : BTreeSet<NfaGuardedConfiguration<Data>>)
+            -> BTreeSet<NfaGuardedConfiguration<Data>> {
+    let mut closure = BTreeSet::new();
+    let mut to_iter : Vec<NfaGuardedConfiguration<Data>> = configurations.into_iter().collect();
+    while let Some(next) = to_iter.pop() {
+        if closure.insert(next.clone()) {
+            if let Some(targets) = nfa.epsilon_trans.get(next.0) {
+                for target in targets {
+                    to_iter.push((*target,next.1.clone()));
+                }
+            }
+        }
+    }
+    closure
+}
+
+/// Returns the configurations reached by firing the transitions labelled by the letter
+/// and whether the letter could have been read if the guards were ignored.
+/// Firing a transition returns the updated data, or None if its guards are not satisfied.
+pub fn get_guarded_successors<Data, Fire>(nfa : &AutNFAIT<usize>,
+                                          configurations : &BTreeSet<NfaGuardedConfiguration<Data>>,
+                                          letter : usize,
+                                          fire : Fire) -> (BTreeSet<NfaGuardedConfiguration<Data>>,bool)
+        where Data : Clone + Ord,
+              Fire : Fn(&NfaGuardedTransition,&Data) -> Option<Data> {
+    let mut reached = BTreeSet::new();
+    let mut unguarded_enabled = false;
+    for (state,data) in configurations {
+        if let Some(targets) = nfa.transitions[*state].get(&letter) {
+            for target in targets {
+                unguarded_enabled = true;
+                if let Some(new_data) = fire(&(*state,letter,*target),data) {
+                    reached.insert((*target,new_data));
+                }
+            }
+        }
+    }
+    (get_guarded_epsilon_closure(nfa,reached),unguarded_enabled)
+}
+
+/// Resets on the states given by the reset policy, w.r.t. the letters of the events, with the given data.
+pub fn get_guarded_reset_configurations<Data : Clone + Ord, Payload>(nfa : &AutNFAIT<usize>,
+                                                                     reset : &NfaWordAnalysisResetOn,
+                                                                     history : &[(usize,Payload)],
+                                                                     upcoming : &[(usize,Payload)],
+                                                                     data : Data) -> BTreeSet<NfaGuardedConfiguration<Data>> {
+    let (history,upcoming) = reset.get_lookaround(history,upcoming);
+    let history : Vec<usize> = history.iter().map(|(letter,_)| *letter).collect();
+    let upcoming : Vec<usize> = upcoming.iter().map(|(letter,_)| *letter).collect();
+    let states = reset.get_reset_states(nfa,&history,&upcoming);
+    get_guarded_epsilon_closure(nfa,states.into_iter().map(|st| (st,data.clone())).collect())
+}
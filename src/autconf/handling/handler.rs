@@ -0,0 +1,160 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use graph_process_manager_core::delegate::node::GenericNode;
+use graph_process_manager_core::handler::handler::AbstractProcessHandler;
+use graph_process_manager_core::queued_steps::step::GenericStep;
+
+use crate::autana::filter::filter::NfaWordAnalysisFilterCriterion;
+use crate::autana::handling::policy::{classify_deviation, get_policy_steps};
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
+use crate::autconf::conf::{NfaConfigurationAnalysisConfig, NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof};
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::node::NfaConfigurationAnalysisNodeKind;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autconf::step::NfaConfigurationAnalysisStepKind;
+use crate::autconf::verdict::local::NfaConfigurationAnalysisLocalVerdict;
+
+
+pub struct NfaConfigurationAnalysisProcessHandler {}
+
+impl<Semantics : NfaConfigurationSemantics,
+     Printer : AbstractLanguagePrinter<usize>>
+    AbstractProcessHandler<NfaConfigurationAnalysisConfig<Semantics,Printer>> for NfaConfigurationAnalysisProcessHandler {
+
+    fn process_new_step(context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                        param : &NfaWordAnalysisParameterization,
+                        parent_state: &GenericNode<NfaConfigurationAnalysisNodeKind<Semantics::Configurations>>,
+                        step_to_process: &GenericStep<NfaConfigurationAnalysisStepKind<Semantics::Configurations>>,
+                        _new_state_id: u32,
+                        _node_counter: u32) -> NfaConfigurationAnalysisNodeKind<Semantics::Configurations> {
+        let parent_pos = parent_state.kind.pos_in_trace;
+        match &step_to_process.kind {
+            NfaConfigurationAnalysisStepKind::ReadNext(new_active) => {
+                NfaConfigurationAnalysisNodeKind::new(new_active.clone(),parent_pos + 1)
+            },
+            NfaConfigurationAnalysisStepKind::ResetAndOrSkip(may_reset,may_skip) => {
+                let new_pos = if *may_skip { parent_pos + 1 } else { parent_pos };
+                if *may_reset {
+                    let reset_configurations = context.get_reset_configurations(param.policy.get_reset_policy().unwrap(),
+                                                                                parent_pos,
+                                                                                new_pos);
+                    NfaConfigurationAnalysisNodeKind::new(reset_configurations,new_pos)
+                } else {
+                    NfaConfigurationAnalysisNodeKind::new(parent_state.kind.active_configurations.clone(),new_pos)
+                }
+            },
+            NfaConfigurationAnalysisStepKind::ResetOnAlternativeAndOrSkip(alternative,may_skip) => {
+                let new_pos = if *may_skip { parent_pos + 1 } else { parent_pos };
+                let reset_configurations = context.get_reset_configurations(param.policy.get_reset_alternative(*alternative).unwrap(),
+                                                                            parent_pos,
+                                                                            new_pos);
+                NfaConfigurationAnalysisNodeKind::new(reset_configurations,new_pos)
+            }
+        }
+    }
+
+    fn get_criterion(_context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                     _param : &NfaWordAnalysisParameterization,
+                     _parent_state: &GenericNode<NfaConfigurationAnalysisNodeKind<Semantics::Configurations>>,
+                     _step_to_process: &GenericStep<NfaConfigurationAnalysisStepKind<Semantics::Configurations>>,
+                     _new_state_id: u32,
+                     _node_counter: u32) -> NfaWordAnalysisFilterCriterion {
+        NfaWordAnalysisFilterCriterion{}
+    }
+
+    fn collect_next_steps(context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                          param : &NfaWordAnalysisParameterization,
+                          parent_node_kind: &NfaConfigurationAnalysisNodeKind<Semantics::Configurations>)
+                -> Vec<NfaConfigurationAnalysisStepKind<Semantics::Configurations>> {
+        let pos = parent_node_kind.pos_in_trace;
+        if pos >= context.word.len() {
+            return vec![];
+        }
+        if let Ok(new_active) = context.read_event(&parent_node_kind.active_configurations,pos) {
+            return vec![NfaConfigurationAnalysisStepKind::ReadNext(new_active)];
+        }
+        // here the event cannot be read from the active configurations
+        // events are never swapped (see make_init_node in the context)
+        get_policy_steps(&param.policy,
+                         |reset| context.get_reset_configurations(reset,pos,pos),
+                         |reset_active| is_reset_of_use(context,parent_node_kind,reset_active))
+            .unwrap_or_default()
+            .into_iter().map(NfaConfigurationAnalysisStepKind::from).collect()
+    }
+
+    fn get_local_verdict_when_no_child(context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                       _param : &NfaWordAnalysisParameterization,
+                                       node_kind: &NfaConfigurationAnalysisNodeKind<Semantics::Configurations>)
+                -> NfaConfigurationAnalysisLocalVerdict<Semantics::Violation> {
+        let verdict = if context.word.get(node_kind.pos_in_trace).is_some() {
+            NfaWordAnalysisLocalVerdict::FailureToEmptyTrace
+        } else if context.semantics.is_accepting(&node_kind.active_configurations) {
+            NfaWordAnalysisLocalVerdict::EmptiedTrace
+        } else {
+            NfaWordAnalysisLocalVerdict::PrematureEnd
+        };
+        NfaConfigurationAnalysisLocalVerdict::Word(verdict)
+    }
+
+    fn get_local_verdict_from_static_analysis(context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                              param : &NfaWordAnalysisParameterization,
+                                              node_kind: &mut NfaConfigurationAnalysisNodeKind<Semantics::Configurations>)
+            -> Option<(NfaConfigurationAnalysisLocalVerdict<Semantics::Violation>,NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof)> {
+        let next = Self::collect_next_steps(context,param,node_kind);
+        match next.first() {
+            None | Some(NfaConfigurationAnalysisStepKind::ReadNext(_)) => {
+                None
+            },
+            Some(_) => {
+                let is_known_letter = context.semantics.is_known_letter(&Semantics::get_letter(&context.word[node_kind.pos_in_trace]));
+                let verdict = match context.read_event(&node_kind.active_configurations,node_kind.pos_in_trace) {
+                    Err(Some(violation)) if is_known_letter => {
+                        // the letter is expected but the event violates the semantics
+                        NfaConfigurationAnalysisLocalVerdict::Violation(violation)
+                    },
+                    _ => {
+                        let kind = classify_deviation(&param.policy,is_known_letter,next.iter().any(|step| step.skips_letter()));
+                        NfaConfigurationAnalysisLocalVerdict::Word(NfaWordAnalysisLocalVerdict::Deviation(kind))
+                    }
+                };
+                Some((verdict,NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof{}))
+            }
+        }
+    }
+
+    fn pursue_process_after_static_verdict(_context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                           _param : &NfaWordAnalysisParameterization,
+                                           _loc_verd: &NfaConfigurationAnalysisLocalVerdict<Semantics::Violation>) -> bool {
+        true
+    }
+}
+
+
+/// A reset is of use if it changes the active configurations and allows reading the offending event.
+fn is_reset_of_use<Semantics : NfaConfigurationSemantics,
+                   Printer : AbstractLanguagePrinter<usize>>(context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                                             node_kind: &NfaConfigurationAnalysisNodeKind<Semantics::Configurations>,
+                                                             reset_active : &Semantics::Configurations) -> bool {
+    if context.semantics.is_included(reset_active,&node_kind.active_configurations) {
+        false
+    } else {
+        context.read_event(reset_active,node_kind.pos_in_trace).is_ok()
+    }
+}
@@ -0,0 +1,147 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_loggers::graphviz::drawer::GraphVizProcessDrawer;
+use graph_process_manager_loggers::graphviz::format::GraphVizLoggerNodeFormat;
+use graphviz_dot_builder::colors::GraphvizColor;
+use graphviz_dot_builder::item::cluster::GraphVizCluster;
+use graphviz_dot_builder::item::node::node::GraphVizNode;
+use graphviz_dot_builder::item::node::style::{GraphvizNodeStyleItem, GvNodeShape};
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::loggers::glog::drawer::get_word_analysis_verdict_color;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autconf::conf::{NfaConfigurationAnalysisConfig, NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof};
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::node::NfaConfigurationAnalysisNodeKind;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autconf::step::NfaConfigurationAnalysisStepKind;
+use crate::autconf::verdict::local::NfaConfigurationAnalysisLocalVerdict;
+
+
+/// Draws each node as its active configurations and the remaining events.
+pub struct NfaConfigurationAnalysisProcessDrawer {
+    pub temp_folder : String
+}
+
+impl NfaConfigurationAnalysisProcessDrawer {
+    pub fn new(temp_folder: String) -> Self {
+        Self { temp_folder }
+    }
+}
+
+impl<Semantics : NfaConfigurationSemantics,
+     Printer : AbstractLanguagePrinter<usize>>
+        GraphVizProcessDrawer<NfaConfigurationAnalysisConfig<Semantics,Printer>> for NfaConfigurationAnalysisProcessDrawer {
+
+    fn repr_static_analysis(&self) -> bool {
+        false
+    }
+
+    fn get_temp_folder(&self) -> &str {
+        &self.temp_folder
+    }
+
+    fn get_verdict_color(&self,
+                         local_verdict: &NfaConfigurationAnalysisLocalVerdict<Semantics::Violation>) -> GraphvizColor {
+        match local_verdict {
+            NfaConfigurationAnalysisLocalVerdict::Word(verdict) => {
+                get_word_analysis_verdict_color(verdict)
+            },
+            NfaConfigurationAnalysisLocalVerdict::Violation(_) => {
+                GraphvizColor::blue
+            }
+        }
+    }
+
+    fn make_static_analysis_as_gvcluster(&self,
+                                         _context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                         _param : &NfaWordAnalysisParameterization,
+                                         _parent_state_id: u32,
+                                         _verdict: &NfaConfigurationAnalysisLocalVerdict<Semantics::Violation>,
+                                         _data_proof: &NfaConfigurationAnalysisStaticLocalVerdictAnalysisProof) -> GraphVizCluster {
+        panic!("should not be called")
+    }
+
+    fn make_step_gvnode(&self,
+                        _context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                        _param : &NfaWordAnalysisParameterization,
+                        origin_state_id: u32,
+                        target_state_id: u32,
+                        step: &NfaConfigurationAnalysisStepKind<Semantics::Configurations>) -> GraphVizNode {
+        let style = vec![
+            GraphvizNodeStyleItem::Label(step.to_string()),
+            GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle)
+        ];
+        GraphVizNode::new(format!("s_{}_{}", origin_state_id, target_state_id), style)
+    }
+
+    fn make_node_gvitem_as_gvcluster(&self,
+                                     _context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                     _parameterization: &NfaWordAnalysisParameterization,
+                                     _new_state_id: u32,
+                                     _new_node: &NfaConfigurationAnalysisNodeKind<Semantics::Configurations>) -> GraphVizCluster {
+        panic!("should not be called")
+    }
+
+    fn make_node_gvitem_as_gvnode(&self,
+                                  context: &NfaConfigurationAnalysisContext<Semantics,Printer>,
+                                  _parameterization: &NfaWordAnalysisParameterization,
+                                  new_state_id: u32,
+                                  new_node: &NfaConfigurationAnalysisNodeKind<Semantics::Configurations>) -> GraphVizNode {
+        let remaining = if new_node.pos_in_trace < context.word.len() {
+            let as_letters : Vec<String> = (new_node.pos_in_trace..context.word.len())
+                .map(|pos| context.semantics.event_to_string(&context.word,pos,&context.printer)).collect();
+            as_letters.join(context.printer.get_concatenation_separator(false))
+        } else {
+            context.printer.get_epsilon_symbol(false).to_string()
+        };
+        let style = vec![
+            GraphvizNodeStyleItem::Label(format!("{} <- {}",
+                                                 context.semantics.configurations_to_string(&new_node.active_configurations,
+                                                                                            &context.word,
+                                                                                            new_node.pos_in_trace),
+                                                 remaining)),
+            GraphvizNodeStyleItem::FillColor( GraphvizColor::white ),
+            GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle)
+        ];
+        let node_id = <NfaConfigurationAnalysisProcessDrawer as GraphVizProcessDrawer<NfaConfigurationAnalysisConfig<Semantics,Printer>>>::get_node_id(self, new_state_id);
+        GraphVizNode::new(node_id, style)
+    }
+
+    fn get_node_format(&self) -> &GraphVizLoggerNodeFormat {
+        &GraphVizLoggerNodeFormat::SimpleNode
+    }
+
+    fn get_anchor_id(&self, id: u32) -> String {
+        format!("n{}", id)
+    }
+
+    fn get_node_id(&self, id: u32) -> String {
+        format!("n{}", id)
+    }
+
+    fn get_verdict_id(&self, id: u32) -> String {
+        format!("v{}", id)
+    }
+
+    fn get_static_analysis_ids(&self, _id: u32) -> (String, String) {
+        panic!()
+    }
+
+}
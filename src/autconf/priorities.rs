@@ -0,0 +1,52 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use graph_process_manager_core::delegate::priorities::AbstractPriorities;
+
+use crate::autana::priorities::NfaWordAnalysisPriorities;
+use crate::autconf::step::NfaConfigurationAnalysisStepKind;
+
+
+// the priorities of the analysis of words also apply to configurations
+impl<Configurations> AbstractPriorities<NfaConfigurationAnalysisStepKind<Configurations>> for NfaWordAnalysisPriorities {
+    fn get_priority_of_step(&self, step: &NfaConfigurationAnalysisStepKind<Configurations>) -> i32 {
+        match step {
+            NfaConfigurationAnalysisStepKind::ReadNext(_) => {
+                self.read
+            },
+            NfaConfigurationAnalysisStepKind::ResetAndOrSkip(reset,skip) => {
+                let mut priority = 0;
+                if *reset {
+                    priority += self.reset;
+                }
+                if *skip {
+                    priority += self.skip;
+                }
+                priority
+            },
+            NfaConfigurationAnalysisStepKind::ResetOnAlternativeAndOrSkip(_,skip) => {
+                if *skip {
+                    self.reset + self.skip
+                } else {
+                    self.reset
+                }
+            }
+        }
+    }
+}
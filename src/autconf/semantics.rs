@@ -0,0 +1,79 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::param::NfaWordAnalysisResetOn;
+
+
+/// The semantics of an automaton whose runs go through configurations which are richer than its states,
+/// e.g. states alongside the values of clocks or registers, or the states of several synchronized components.
+/// A word of events is analyzed against it as a word of letters is analyzed against an NFA,
+/// the analysis only relying on how events are read from the active configurations.
+pub trait NfaConfigurationSemantics {
+    // an event of the word i.e. a letter possibly alongside some data
+    type Event;
+    // the configurations which are active at some point of the analysis
+    type Configurations : Clone + PartialEq + Eq + Hash + Debug;
+    // the deviations which are specific to the semantics,
+    // i.e. which occur although the letter could be read from the active states
+    type Violation : Clone + PartialEq + Eq + PartialOrd + Ord + Hash + Debug + fmt::Display;
+
+    fn get_letter(event : &Self::Event) -> usize;
+
+    /// Whether some transition is labelled by the letter.
+    fn is_known_letter(&self, letter : &usize) -> bool;
+
+    /// Returns the configurations on which to reset,
+    /// knowing the events of the word that precede the deviation
+    /// and those that remain to be read after the reset.
+    fn get_reset_configurations(&self,
+                                reset : &NfaWordAnalysisResetOn,
+                                history : &[Self::Event],
+                                upcoming : &[Self::Event]) -> Self::Configurations;
+
+    /// Reads the event at the given position of the word from the configurations.
+    /// The configurations which are returned allow at least one run to go on.
+    /// Otherwise, returns the violation which prevents reading the event, or None if its letter cannot be read at all.
+    fn read_event(&self,
+                  configurations : &Self::Configurations,
+                  word : &[Self::Event],
+                  pos_in_trace : usize) -> Result<Self::Configurations,Option<Self::Violation>>;
+
+    /// Whether all the runs of the configurations are already among the other ones.
+    fn is_included(&self,
+                   configurations : &Self::Configurations,
+                   others : &Self::Configurations) -> bool;
+
+    /// Whether the word is accepted if it ends in the configurations.
+    fn is_accepting(&self, configurations : &Self::Configurations) -> bool;
+
+    /// Prints the configurations which are active before reading the event at the given position of the word.
+    fn configurations_to_string(&self,
+                                configurations : &Self::Configurations,
+                                word : &[Self::Event],
+                                pos_in_trace : usize) -> String;
+
+    /// Prints the event at the given position of the word.
+    fn event_to_string<Printer : AbstractLanguagePrinter<usize>>(&self,
+                                                                 word : &[Self::Event],
+                                                                 pos_in_trace : usize,
+                                                                 printer : &Printer) -> String;
+}
@@ -0,0 +1,93 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use crate::autana::handling::policy::NfaWordAnalysisPolicyStep;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaConfigurationAnalysisStepKind<Configurations> {
+    // ***
+    // read the next event and go to the next active configurations
+    ReadNext(Configurations),
+    // ***
+    // as in the analysis of words, resetting forgets the data accumulated along the runs
+    ResetAndOrSkip(bool,bool),
+    ResetOnAlternativeAndOrSkip(usize,bool)
+}
+
+impl<Configurations> From<NfaWordAnalysisPolicyStep> for NfaConfigurationAnalysisStepKind<Configurations> {
+    fn from(step : NfaWordAnalysisPolicyStep) -> Self {
+        match step {
+            NfaWordAnalysisPolicyStep::ResetAndOrSkip(reset,skip) => {
+                NfaConfigurationAnalysisStepKind::ResetAndOrSkip(reset,skip)
+            },
+            NfaWordAnalysisPolicyStep::ResetOnAlternativeAndOrSkip(alternative,skip) => {
+                NfaConfigurationAnalysisStepKind::ResetOnAlternativeAndOrSkip(alternative,skip)
+            }
+        }
+    }
+}
+
+impl<Configurations> NfaConfigurationAnalysisStepKind<Configurations> {
+    pub fn skips_letter(&self) -> bool {
+        match self {
+            NfaConfigurationAnalysisStepKind::ReadNext(_) => {
+                false
+            },
+            NfaConfigurationAnalysisStepKind::ResetAndOrSkip(_,skip) |
+            NfaConfigurationAnalysisStepKind::ResetOnAlternativeAndOrSkip(_,skip) => {
+                *skip
+            }
+        }
+    }
+}
+
+impl<Configurations> fmt::Display for NfaConfigurationAnalysisStepKind<Configurations> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaConfigurationAnalysisStepKind::ReadNext(_) => {
+                write!(f,"read")
+            },
+            NfaConfigurationAnalysisStepKind::ResetAndOrSkip(reset,skip) => {
+                match (reset,skip) {
+                    (true,true) => {
+                        write!(f,"skip and reset")
+                    },
+                    (true,false) => {
+                        write!(f,"reset")
+                    },
+                    (false,true) => {
+                        write!(f,"skip")
+                    },
+                    (false,false) => {
+                        panic!()
+                    }
+                }
+            },
+            NfaConfigurationAnalysisStepKind::ResetOnAlternativeAndOrSkip(alternative,skip) => {
+                if *skip {
+                    write!(f,"skip and reset #{:}",alternative)
+                } else {
+                    write!(f,"reset #{:}",alternative)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
+
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autconf::verdict::local::NfaConfigurationAnalysisLocalVerdict;
+
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct NfaConfigurationAnalysisGlobalVerdict<Violation : Ord> {
+    // the deviations of all kinds are counted in the verdict of the word
+    pub word_verdict : NfaWordAnalysisGlobalVerdict,
+    // ***
+    // number of deviations per kind specific to the semantics
+    pub violations : BTreeMap<Violation,u32>
+}
+
+impl<Violation : Ord> NfaConfigurationAnalysisGlobalVerdict<Violation> {
+    pub fn new(word_verdict: NfaWordAnalysisGlobalVerdict, violations: BTreeMap<Violation, u32>) -> Self {
+        Self { word_verdict, violations }
+    }
+
+    pub fn get_violations(&self, violation : &Violation) -> u32 {
+        self.violations.get(violation).cloned().unwrap_or(0)
+    }
+}
+
+impl<Violation : Ord + fmt::Display> fmt::Display for NfaConfigurationAnalysisGlobalVerdict<Violation> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let details = self.violations.iter()
+            .map(|(violation,count)| {
+                if *count == 1 {
+                    format!("1 {:}",violation)
+                } else {
+                    format!("{:} {:}s",count,violation)
+                }
+            }).collect();
+        self.word_verdict.fmt_with_details(f,details)
+    }
+}
+
+impl<Violation : Clone + Ord + fmt::Display> AbstractGlobalVerdict<NfaConfigurationAnalysisLocalVerdict<Violation>>
+            for NfaConfigurationAnalysisGlobalVerdict<Violation> {
+
+    fn is_verdict_pertinent_for_process() -> bool {
+        true
+    }
+
+    fn get_baseline_verdict() -> Self {
+        NfaConfigurationAnalysisGlobalVerdict::new(NfaWordAnalysisGlobalVerdict::get_baseline_verdict(),BTreeMap::new())
+    }
+
+    fn update_with_local_verdict(self,
+                                 local_verdict: &NfaConfigurationAnalysisLocalVerdict<Violation>) -> Self {
+        match local_verdict {
+            NfaConfigurationAnalysisLocalVerdict::Word(verdict) => {
+                NfaConfigurationAnalysisGlobalVerdict::new(self.word_verdict.update_with_local_verdict(verdict),
+                                                           self.violations)
+            },
+            NfaConfigurationAnalysisLocalVerdict::Violation(violation) => {
+                let word_verdict = NfaWordAnalysisGlobalVerdict{deviations:self.word_verdict.deviations + 1,
+                                                                ..self.word_verdict};
+                let mut violations = self.violations;
+                *violations.entry(violation.clone()).or_insert(0) += 1;
+                NfaConfigurationAnalysisGlobalVerdict::new(word_verdict,violations)
+            }
+        }
+    }
+
+    fn is_goal_reached(&self,
+                       _goal: &Option<Self>) -> bool {
+        false
+    }
+
+    fn update_knowing_nodes_were_filtered_out(self,
+                                              _has_filtered_nodes: bool) -> Self {
+        self
+    }
+
+}
@@ -0,0 +1,41 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fmt;
+
+use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaConfigurationAnalysisLocalVerdict<Violation> {
+    // a verdict which also occurs in the analysis of words
+    Word(NfaWordAnalysisLocalVerdict),
+    // a deviation which is specific to the semantics
+    Violation(Violation)
+}
+
+impl<Violation : fmt::Display> fmt::Display for NfaConfigurationAnalysisLocalVerdict<Violation> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaConfigurationAnalysisLocalVerdict::Word(verdict) => {
+                write!(f,"{:}",verdict)
+            },
+            NfaConfigurationAnalysisLocalVerdict::Violation(violation) => {
+                write!(f,"Deviation({:})",violation)
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autconf::guarded::{NfaGuardedConfiguration, NfaGuardedTransition};
+use crate::autdata::guard::{NfaDataGuard, NfaDataRegisters};
+
+
+/// A transition given by its origin state, its letter and its target state.
+pub type NfaDataTransition = NfaGuardedTransition;
+
+/// An active state alongside the values stored in the registers along the run reaching it.
+pub type NfaDataConfiguration = NfaGuardedConfiguration<NfaDataRegisters>;
+
+/// An NFA whose transitions may carry guards over the payloads of events
+/// and bind values of the payloads to registers, in the style of register automata.
+/// Epsilon transitions carry neither guards nor bindings.
+pub struct NfaDataAutomaton {
+    pub nfa : AutNFAIT<usize>,
+    pub guards : HashMap<NfaDataTransition,Vec<NfaDataGuard>>,
+    // registers in which to store the value at a given index of the payload
+    pub bindings : HashMap<NfaDataTransition,Vec<(String,usize)>>
+}
+
+impl NfaDataAutomaton {
+    pub fn new(nfa: AutNFAIT<usize>) -> Self {
+        Self { nfa, guards : HashMap::new(), bindings : HashMap::new() }
+    }
+
+    pub fn with_guard(mut self, transition : NfaDataTransition, guard : NfaDataGuard) -> Self {
+        self.guards.entry(transition).or_default().push(guard);
+        self
+    }
+
+    pub fn with_binding(mut self, transition : NfaDataTransition, register : String, payload_index : usize) -> Self {
+        self.bindings.entry(transition).or_default().push((register,payload_index));
+        self
+    }
+
+    /// Returns the registers after firing the transition with the payload, or None if its guards are not satisfied.
+    pub fn fire(&self,
+                transition : &NfaDataTransition,
+                registers : &NfaDataRegisters,
+                payload : &[i64]) -> Option<NfaDataRegisters> {
+        let satisfied = self.guards.get(transition)
+            .is_none_or(|guards| guards.iter().all(|guard| guard.is_satisfied(payload,registers)));
+        if !satisfied {
+            return None;
+        }
+        let mut new_registers = registers.clone();
+        if let Some(bindings) = self.bindings.get(transition) {
+            for (register,payload_index) in bindings {
+                if let Some(value) = payload.get(*payload_index) {
+                    new_registers.insert(register.clone(),*value);
+                }
+            }
+        }
+        Some(new_registers)
+    }
+}
@@ -0,0 +1,67 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Formatter;
+
+
+/// Values of the registers, by name.
+pub type NfaDataRegisters = BTreeMap<String,i64>;
+
+/// A predicate over the payload of an event and the values of the registers.
+pub type NfaDataPredicate = Box<dyn Fn(&[i64],&NfaDataRegisters) -> bool>;
+
+/// A guard on a transition.
+/// The description is used when printing the guard.
+pub struct NfaDataGuard {
+    pub description : String,
+    pub predicate : NfaDataPredicate
+}
+
+impl NfaDataGuard {
+    pub fn new(description: String, predicate: NfaDataPredicate) -> Self {
+        Self { description, predicate }
+    }
+
+    /// The value at the given index of the payload must equal the value stored in the register.
+    /// The guard is not satisfied if the register was not bound along the run.
+    pub fn register_equals(register : String, payload_index : usize) -> Self {
+        let description = format!("p{:} = {:}",payload_index,register);
+        Self::new(description,
+                  Box::new(move |payload, registers| {
+                      match (payload.get(payload_index),registers.get(&register)) {
+                          (Some(value),Some(stored)) => {
+                              value == stored
+                          },
+                          _ => {
+                              false
+                          }
+                      }
+                  }))
+    }
+
+    pub fn is_satisfied(&self, payload : &[i64], registers : &NfaDataRegisters) -> bool {
+        (self.predicate)(payload,registers)
+    }
+}
+
+impl fmt::Display for NfaDataGuard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,"[{:}]",self.description)
+    }
+}
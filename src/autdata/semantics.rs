@@ -0,0 +1,127 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::param::NfaWordAnalysisResetOn;
+use crate::autconf::conf::NfaConfigurationAnalysisConfig;
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::guarded::{get_guarded_reset_configurations, get_guarded_successors};
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autdata::automaton::{NfaDataAutomaton, NfaDataConfiguration};
+
+
+/// Deviations which occur when the letter can be read from the active states but not with its payload.
+#[derive(Clone, PartialEq, Debug, Eq, PartialOrd, Ord, Hash)]
+pub enum NfaDataViolation {
+    // the payload does not satisfy the guards
+    GuardViolation
+}
+
+impl fmt::Display for NfaDataViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaDataViolation::GuardViolation => {
+                write!(f,"guard violation")
+            }
+        }
+    }
+}
+
+pub type NfaDataAnalysisContext<Printer> = NfaConfigurationAnalysisContext<NfaDataAutomaton,Printer>;
+
+pub type NfaDataAnalysisConfig<Printer> = NfaConfigurationAnalysisConfig<NfaDataAutomaton,Printer>;
+
+/// Events are letters alongside their payloads.
+impl NfaConfigurationSemantics for NfaDataAutomaton {
+    type Event = (usize,Vec<i64>);
+    type Configurations = BTreeSet<NfaDataConfiguration>;
+    type Violation = NfaDataViolation;
+
+    fn get_letter(event : &(usize,Vec<i64>)) -> usize {
+        event.0
+    }
+
+    fn is_known_letter(&self, letter : &usize) -> bool {
+        self.nfa.transitions.iter().any(|outgoing| outgoing.contains_key(letter))
+    }
+
+    /// Resetting empties the registers.
+    fn get_reset_configurations(&self,
+                                reset : &NfaWordAnalysisResetOn,
+                                history : &[(usize,Vec<i64>)],
+                                upcoming : &[(usize,Vec<i64>)]) -> BTreeSet<NfaDataConfiguration> {
+        get_guarded_reset_configurations(&self.nfa,reset,history,upcoming,Default::default())
+    }
+
+    fn read_event(&self,
+                  configurations : &BTreeSet<NfaDataConfiguration>,
+                  word : &[(usize,Vec<i64>)],
+                  pos_in_trace : usize) -> Result<BTreeSet<NfaDataConfiguration>,Option<NfaDataViolation>> {
+        let (letter,payload) = &word[pos_in_trace];
+        let (reached,unguarded_enabled) = get_guarded_successors(&self.nfa,
+                                                                 configurations,
+                                                                 *letter,
+                                                                 |transition,registers| self.fire(transition,registers,payload));
+        if !reached.is_empty() {
+            Ok(reached)
+        } else if unguarded_enabled {
+            Err(Some(NfaDataViolation::GuardViolation))
+        } else {
+            Err(None)
+        }
+    }
+
+    fn is_included(&self,
+                   configurations : &BTreeSet<NfaDataConfiguration>,
+                   others : &BTreeSet<NfaDataConfiguration>) -> bool {
+        configurations.is_subset(others)
+    }
+
+    fn is_accepting(&self, configurations : &BTreeSet<NfaDataConfiguration>) -> bool {
+        configurations.iter().any(|(state,_)| self.nfa.finals.contains(state))
+    }
+
+    /// Each state is printed with the values of its registers.
+    fn configurations_to_string(&self,
+                                configurations : &BTreeSet<NfaDataConfiguration>,
+                                _word : &[(usize,Vec<i64>)],
+                                _pos_in_trace : usize) -> String {
+        let as_strings : Vec<String> = configurations.iter()
+            .map(|(state,registers)| {
+                if registers.is_empty() {
+                    format!("S{}",state)
+                } else {
+                    let values : Vec<String> = registers.iter().map(|(r,v)| format!("{}={}",r,v)).collect();
+                    format!("S{}[{}]",state,values.join(","))
+                }
+            }).collect();
+        format!("{{{}}}",as_strings.join(","))
+    }
+
+    fn event_to_string<Printer : AbstractLanguagePrinter<usize>>(&self,
+                                                                 word : &[(usize,Vec<i64>)],
+                                                                 pos_in_trace : usize,
+                                                                 printer : &Printer) -> String {
+        let event = &word[pos_in_trace];
+        let values : Vec<String> = event.1.iter().map(|v| v.to_string()).collect();
+        format!("{}({})",printer.get_letter_string_repr(&event.0),values.join(","))
+    }
+}
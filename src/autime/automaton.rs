@@ -0,0 +1,85 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autconf::guarded::{NfaGuardedConfiguration, NfaGuardedTransition};
+use crate::autime::guard::{NfaDelayGuard, NfaDelayReference};
+
+
+/// A transition given by its origin state, its letter and its target state.
+pub type NfaTimedTransition = NfaGuardedTransition;
+
+/// An active state alongside the timestamps at which the clocks were last reset along the run reaching it.
+pub type NfaTimedConfiguration = NfaGuardedConfiguration<BTreeMap<String,u64>>;
+
+/// An NFA whose transitions may carry delay guards and reset named clocks.
+/// Epsilon transitions carry neither guards nor resets.
+pub struct NfaTimedAutomaton {
+    pub nfa : AutNFAIT<usize>,
+    pub guards : HashMap<NfaTimedTransition,Vec<NfaDelayGuard>>,
+    pub clock_resets : HashMap<NfaTimedTransition,Vec<String>>
+}
+
+impl NfaTimedAutomaton {
+    pub fn new(nfa: AutNFAIT<usize>) -> Self {
+        Self { nfa, guards : HashMap::new(), clock_resets : HashMap::new() }
+    }
+
+    pub fn with_guard(mut self, transition : NfaTimedTransition, guard : NfaDelayGuard) -> Self {
+        self.guards.entry(transition).or_default().push(guard);
+        self
+    }
+
+    pub fn with_clock_reset(mut self, transition : NfaTimedTransition, clock : String) -> Self {
+        self.clock_resets.entry(transition).or_default().push(clock);
+        self
+    }
+
+    /// Returns the names of the clocks which are reset or referred to by some guard.
+    pub fn get_clocks(&self) -> BTreeSet<String> {
+        let reset = self.clock_resets.values().flatten().cloned();
+        let referred = self.guards.values().flatten()
+            .filter_map(|guard| match &guard.reference {
+                NfaDelayReference::Clock(clock) => Some(clock.clone()),
+                NfaDelayReference::PreviousEvent => None
+            });
+        reset.chain(referred).collect()
+    }
+
+    /// Returns the clocks after firing the transition at the given time, or None if its delay guards are not satisfied.
+    pub fn fire(&self,
+                transition : &NfaTimedTransition,
+                clocks : &BTreeMap<String,u64>,
+                previous_timestamp : Option<u64>,
+                timestamp : u64) -> Option<BTreeMap<String,u64>> {
+        let satisfied = self.guards.get(transition)
+            .is_none_or(|guards| guards.iter().all(|guard| guard.is_satisfied(clocks,previous_timestamp,timestamp)));
+        if !satisfied {
+            return None;
+        }
+        let mut new_clocks = clocks.clone();
+        if let Some(resets) = self.clock_resets.get(transition) {
+            for clock in resets {
+                new_clocks.insert(clock.clone(),timestamp);
+            }
+        }
+        Some(new_clocks)
+    }
+}
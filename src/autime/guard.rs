@@ -0,0 +1,112 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Formatter;
+
+
+/// What the delay before an event is measured from.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaDelayReference {
+    // the event which precedes in the word
+    PreviousEvent,
+    // the last time the named clock was reset along the run
+    Clock(String)
+}
+
+impl fmt::Display for NfaDelayReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaDelayReference::PreviousEvent => {
+                write!(f,"previous event")
+            },
+            NfaDelayReference::Clock(clock) => {
+                write!(f,"clock {:}",clock)
+            }
+        }
+    }
+}
+
+/// Bounds (inclusive) on the delay before an event.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NfaDelayGuard {
+    pub reference : NfaDelayReference,
+    pub min : Option<u64>,
+    pub max : Option<u64>
+}
+
+impl NfaDelayGuard {
+    pub fn new(reference: NfaDelayReference, min: Option<u64>, max: Option<u64>) -> Self {
+        Self { reference, min, max }
+    }
+
+    /// Clocks are started at the beginning of the run hence a guard on a clock which was
+    /// never reset along the run measures the delay since that beginning.
+    /// The first event of the word has no previous event, hence guards on it which
+    /// are relative to the previous event are satisfied.
+    /// Timestamps are expected not to decrease: a reference point which is after the event does not satisfy the guard.
+    pub fn is_satisfied(&self,
+                        clocks : &BTreeMap<String,u64>,
+                        previous_timestamp : Option<u64>,
+                        timestamp : u64) -> bool {
+        let reference = match &self.reference {
+            NfaDelayReference::PreviousEvent => {
+                match previous_timestamp {
+                    None => {
+                        return true;
+                    },
+                    Some(previous) => {
+                        previous
+                    }
+                }
+            },
+            NfaDelayReference::Clock(clock) => {
+                match clocks.get(clock) {
+                    None => {
+                        return false;
+                    },
+                    Some(reset_at) => {
+                        *reset_at
+                    }
+                }
+            }
+        };
+        timestamp.checked_sub(reference).is_some_and(|delay| {
+            self.min.is_none_or(|min| delay >= min) && self.max.is_none_or(|max| delay <= max)
+        })
+    }
+}
+
+impl fmt::Display for NfaDelayGuard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.min,self.max) {
+            (None,None) => {
+                write!(f,"any delay after {:}",self.reference)
+            },
+            (Some(min),None) => {
+                write!(f,"at least {:} after {:}",min,self.reference)
+            },
+            (None,Some(max)) => {
+                write!(f,"within {:} after {:}",max,self.reference)
+            },
+            (Some(min),Some(max)) => {
+                write!(f,"between {:} and {:} after {:}",min,max,self.reference)
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::param::NfaWordAnalysisResetOn;
+use crate::autconf::conf::NfaConfigurationAnalysisConfig;
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::guarded::{get_guarded_reset_configurations, get_guarded_successors};
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autime::automaton::{NfaTimedAutomaton, NfaTimedConfiguration};
+
+
+/// Deviations which occur when the letter can be read from the active states but not at the time it occurred.
+#[derive(Clone, PartialEq, Debug, Eq, PartialOrd, Ord, Hash)]
+pub enum NfaTimedViolation {
+    // the letter occurs too early or too late w.r.t. the delay guards
+    DelayViolation,
+    // the event is timestamped before the event which precedes it in the word
+    NonMonotonicTimestamp
+}
+
+impl fmt::Display for NfaTimedViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaTimedViolation::DelayViolation => {
+                write!(f,"timing violation")
+            },
+            NfaTimedViolation::NonMonotonicTimestamp => {
+                write!(f,"non monotonic timestamp")
+            }
+        }
+    }
+}
+
+pub type NfaTimedAnalysisContext<Printer> = NfaConfigurationAnalysisContext<NfaTimedAutomaton,Printer>;
+
+pub type NfaTimedAnalysisConfig<Printer> = NfaConfigurationAnalysisConfig<NfaTimedAutomaton,Printer>;
+
+/// Events are letters alongside the timestamps at which they occurred.
+impl NfaConfigurationSemantics for NfaTimedAutomaton {
+    type Event = (usize,u64);
+    type Configurations = BTreeSet<NfaTimedConfiguration>;
+    type Violation = NfaTimedViolation;
+
+    fn get_letter(event : &(usize,u64)) -> usize {
+        event.0
+    }
+
+    fn is_known_letter(&self, letter : &usize) -> bool {
+        self.nfa.transitions.iter().any(|outgoing| outgoing.contains_key(letter))
+    }
+
+    /// Resetting starts a new run: all the clocks are started at the time of the first upcoming event.
+    fn get_reset_configurations(&self,
+                                reset : &NfaWordAnalysisResetOn,
+                                history : &[(usize,u64)],
+                                upcoming : &[(usize,u64)]) -> BTreeSet<NfaTimedConfiguration> {
+        let clocks = match upcoming.first() {
+            None => {
+                BTreeMap::new()
+            },
+            Some((_,start)) => {
+                self.get_clocks().into_iter().map(|clock| (clock,*start)).collect()
+            }
+        };
+        get_guarded_reset_configurations(&self.nfa,reset,history,upcoming,clocks)
+    }
+
+    /// An event timestamped before the previous one cannot be read whatever the active configurations.
+    fn read_event(&self,
+                  configurations : &BTreeSet<NfaTimedConfiguration>,
+                  word : &[(usize,u64)],
+                  pos_in_trace : usize) -> Result<BTreeSet<NfaTimedConfiguration>,Option<NfaTimedViolation>> {
+        let (letter,timestamp) = word[pos_in_trace];
+        let previous_timestamp = pos_in_trace.checked_sub(1).map(|prev| word[prev].1);
+        if previous_timestamp.is_some_and(|previous| previous > timestamp) {
+            return Err(Some(NfaTimedViolation::NonMonotonicTimestamp));
+        }
+        let (reached,untimed_enabled) = get_guarded_successors(&self.nfa,
+                                                               configurations,
+                                                               letter,
+                                                               |transition,clocks| self.fire(transition,clocks,previous_timestamp,timestamp));
+        if !reached.is_empty() {
+            Ok(reached)
+        } else if untimed_enabled {
+            Err(Some(NfaTimedViolation::DelayViolation))
+        } else {
+            Err(None)
+        }
+    }
+
+    fn is_included(&self,
+                   configurations : &BTreeSet<NfaTimedConfiguration>,
+                   others : &BTreeSet<NfaTimedConfiguration>) -> bool {
+        configurations.is_subset(others)
+    }
+
+    fn is_accepting(&self, configurations : &BTreeSet<NfaTimedConfiguration>) -> bool {
+        configurations.iter().any(|(state,_)| self.nfa.finals.contains(state))
+    }
+
+    /// Each state is printed with the values of its clocks when the event at the given position occurs,
+    /// i.e. the time elapsed since they were last reset (when the word is emptied, at the time of its last event).
+    fn configurations_to_string(&self,
+                                configurations : &BTreeSet<NfaTimedConfiguration>,
+                                word : &[(usize,u64)],
+                                pos_in_trace : usize) -> String {
+        let now = word.get(pos_in_trace).or(word.last()).map(|(_,timestamp)| *timestamp);
+        let as_strings : Vec<String> = configurations.iter()
+            .map(|(state,clocks)| {
+                if clocks.is_empty() {
+                    format!("S{}",state)
+                } else {
+                    let resets : Vec<String> = clocks.iter()
+                        .map(|(c,t)| format!("{}={}",c,elapsed_to_string(*t,now.unwrap_or(*t))))
+                        .collect();
+                    format!("S{}[{}]",state,resets.join(","))
+                }
+            }).collect();
+        format!("{{{}}}",as_strings.join(","))
+    }
+
+    /// Each event is printed with the delay since the previous one.
+    fn event_to_string<Printer : AbstractLanguagePrinter<usize>>(&self,
+                                                                 word : &[(usize,u64)],
+                                                                 pos_in_trace : usize,
+                                                                 printer : &Printer) -> String {
+        let (letter,timestamp) = word[pos_in_trace];
+        match pos_in_trace.checked_sub(1) {
+            None => {
+                printer.get_letter_string_repr(&letter)
+            },
+            Some(prev) => {
+                format!("{}{}",printer.get_letter_string_repr(&letter),elapsed_to_string(word[prev].1,timestamp))
+            }
+        }
+    }
+}
+
+/// Prints the time elapsed from the reference to the timestamp, which is negative if the timestamps decrease.
+fn elapsed_to_string(reference : u64, timestamp : u64) -> String {
+    format!("{:+}",i128::from(timestamp) - i128::from(reference))
+}
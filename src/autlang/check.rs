@@ -0,0 +1,95 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::node::{get_epsilon_closure, get_successors};
+use crate::autlang::param::NfaLanguageConformanceQuantifier;
+use crate::autlang::verdict::NfaLanguageConformanceVerdict;
+
+
+/// A state of the trace NFA alongside the states of the specification NFA
+/// which are active after reading the same word.
+type NfaLanguageProductNode = (usize,BTreeSet<usize>);
+
+/// Checks whether every word or some word of the language of the trace NFA is accepted by the specification NFA.
+/// The product of the trace NFA with the subset construction of the specification NFA is explored on the fly
+/// by increasing word length so that the words given in the verdict are the shortest ones.
+pub fn check_language_conformance(spec : &AutNFAIT<usize>,
+                                  trace : &AutNFAIT<usize>,
+                                  quantifier : &NfaLanguageConformanceQuantifier) -> NfaLanguageConformanceVerdict {
+    let spec_initials = get_epsilon_closure(spec,&spec.initials.iter().cloned().collect());
+    let mut trace_initials : Vec<usize> = trace.initials.iter().cloned().collect();
+    trace_initials.sort();
+    let mut visited : HashSet<NfaLanguageProductNode> = HashSet::new();
+    let mut queue : VecDeque<(NfaLanguageProductNode,Vec<usize>)> = trace_initials.into_iter()
+        .map(|st| ((st,spec_initials.clone()),vec![])).collect();
+    let mut shortest_counterexample : Option<Vec<usize>> = None;
+    while let Some((node,word)) = queue.pop_front() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        let (trace_state,spec_states) = &node;
+        if trace.finals.contains(trace_state) {
+            let accepted = spec_states.iter().any(|st| spec.finals.contains(st));
+            match (quantifier,accepted) {
+                (NfaLanguageConformanceQuantifier::Every,false) => {
+                    return NfaLanguageConformanceVerdict::DoesNotConform(Some(word));
+                },
+                (NfaLanguageConformanceQuantifier::Some,true) => {
+                    return NfaLanguageConformanceVerdict::Conforms(Some(word));
+                },
+                (NfaLanguageConformanceQuantifier::Some,false) => {
+                    if shortest_counterexample.is_none() {
+                        shortest_counterexample = Some(word.clone());
+                    }
+                },
+                (NfaLanguageConformanceQuantifier::Every,true) => {}
+            }
+        }
+        // epsilon transitions of the trace do not lengthen the word hence are explored first
+        if let Some(targets) = trace.epsilon_trans.get(*trace_state) {
+            for target in targets {
+                queue.push_front(((*target,spec_states.clone()),word.clone()));
+            }
+        }
+        if let Some(outgoing) = trace.transitions.get(*trace_state) {
+            let mut letters : Vec<&usize> = outgoing.keys().collect();
+            letters.sort();
+            for letter in letters {
+                let next_spec_states = get_successors(spec,spec_states,letter);
+                let mut targets : Vec<&usize> = outgoing.get(letter).unwrap().iter().collect();
+                targets.sort();
+                for target in targets {
+                    let mut new_word = word.clone();
+                    new_word.push(*letter);
+                    queue.push_back(((*target,next_spec_states.clone()),new_word));
+                }
+            }
+        }
+    }
+    match quantifier {
+        NfaLanguageConformanceQuantifier::Every => {
+            NfaLanguageConformanceVerdict::Conforms(None)
+        },
+        NfaLanguageConformanceQuantifier::Some => {
+            NfaLanguageConformanceVerdict::DoesNotConform(shortest_counterexample)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+
+/// Whether all the words of the trace language or only one of them must conform to the specification.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaLanguageConformanceQuantifier {
+    Every,
+    Some
+}
+
+impl fmt::Display for NfaLanguageConformanceQuantifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaLanguageConformanceQuantifier::Every => {
+                write!(f,"every word conforms")
+            },
+            NfaLanguageConformanceQuantifier::Some => {
+                write!(f,"some word conforms")
+            }
+        }
+    }
+}
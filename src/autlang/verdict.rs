@@ -0,0 +1,59 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaLanguageConformanceVerdict {
+    // with the shortest conforming word if one had to be found
+    Conforms(Option<Vec<usize>>),
+    // with the shortest non-conforming word of the trace language
+    // (if the trace language is empty no word conforms and there is no counterexample)
+    DoesNotConform(Option<Vec<usize>>)
+}
+
+impl NfaLanguageConformanceVerdict {
+    pub fn conforms(&self) -> bool {
+        matches!(self,NfaLanguageConformanceVerdict::Conforms(_))
+    }
+
+    pub fn get_counterexample(&self) -> Option<&Vec<usize>> {
+        match self {
+            NfaLanguageConformanceVerdict::Conforms(_) => {
+                None
+            },
+            NfaLanguageConformanceVerdict::DoesNotConform(counterexample) => {
+                counterexample.as_ref()
+            }
+        }
+    }
+}
+
+impl fmt::Display for NfaLanguageConformanceVerdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaLanguageConformanceVerdict::Conforms(_) => {
+                write!(f,"Conforms")
+            },
+            NfaLanguageConformanceVerdict::DoesNotConform(_) => {
+                write!(f,"DoesNotConform")
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashSet;
+
+
+/// Pairs of letters whose occurrences may be swapped when they are adjacent in a word.
+/// The relation is symmetric and irreflexive.
+#[derive(Clone, PartialEq, Debug, Eq, Default)]
+pub struct NfaIndependenceRelation {
+    pub pairs : HashSet<(usize,usize)>
+}
+
+impl NfaIndependenceRelation {
+    pub fn new(pairs: HashSet<(usize, usize)>) -> Self {
+        let mut relation = Self::default();
+        for (letter1,letter2) in pairs {
+            relation = relation.with_independent(letter1,letter2);
+        }
+        relation
+    }
+
+    pub fn with_independent(mut self, letter1 : usize, letter2 : usize) -> Self {
+        if letter1 != letter2 {
+            self.pairs.insert((letter1,letter2));
+            self.pairs.insert((letter2,letter1));
+        }
+        self
+    }
+
+    pub fn are_independent(&self, letter1 : &usize, letter2 : &usize) -> bool {
+        self.pairs.contains(&(*letter1,*letter2))
+    }
+}
@@ -0,0 +1,170 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use maplit::btreeset;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::node::get_epsilon_closure;
+use crate::autpor::independence::NfaIndependenceRelation;
+
+
+/// A prefix of the partial order, i.e. a set of positions of the word which is closed under dependent predecessors,
+/// given by the number of occurrences of each letter it contains.
+/// As a letter depends on itself, its occurrences are read in the order of the word
+/// hence these counters determine the prefix.
+type NfaTracePrefix = Vec<usize>;
+
+/// The index of a prefix among those already met alongside a state of the NFA.
+/// Linearizations of the same prefix which reach the same state lead to the same configuration,
+/// which is expanded once.
+type NfaTraceConfiguration = (usize,usize);
+
+/// Result of the search for a linearization of the word accepted by the NFA.
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub struct NfaTraceMembership {
+    // positions of the word in the order in which they are read by an accepting run, if any
+    pub linearization : Option<Vec<usize>>,
+    // number of configurations explored
+    pub explored : usize
+}
+
+impl NfaTraceMembership {
+    pub fn new(linearization: Option<Vec<usize>>, explored: usize) -> Self {
+        Self { linearization, explored }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.linearization.is_some()
+    }
+
+    pub fn get_linearized_word(&self, word : &[usize]) -> Option<Vec<usize>> {
+        self.linearization.as_ref().map(|positions| positions.iter().map(|pos| word[*pos]).collect())
+    }
+}
+
+/// The letters of the word and, for each of them, the positions at which it occurs.
+struct NfaTraceOccurrences {
+    letters : Vec<usize>,
+    positions : Vec<Vec<usize>>
+}
+
+impl NfaTraceOccurrences {
+    fn new(word : &[usize]) -> Self {
+        let letters : Vec<usize> = word.iter().cloned().collect::<BTreeSet<usize>>().into_iter().collect();
+        let mut positions = vec![vec![];letters.len()];
+        for (pos,letter) in word.iter().enumerate() {
+            positions[letters.binary_search(letter).unwrap()].push(pos);
+        }
+        Self { letters, positions }
+    }
+
+    /// Returns the next position at which the letter of the given index occurs after the prefix, if any.
+    fn get_next_position(&self, prefix : &NfaTracePrefix, idx : usize) -> Option<usize> {
+        self.positions[idx].get(prefix[idx]).cloned()
+    }
+
+    /// Returns the indices of the letters whose next occurrence can be read after the prefix,
+    /// i.e. which is not preceded by a pending occurrence of a dependent letter,
+    /// in the order of these occurrences in the word.
+    fn get_enabled_letters(&self,
+                           independence : &NfaIndependenceRelation,
+                           prefix : &NfaTracePrefix) -> Vec<(usize,usize)> {
+        let next : Vec<Option<usize>> = (0..self.letters.len()).map(|idx| self.get_next_position(prefix,idx)).collect();
+        let mut enabled : Vec<(usize,usize)> = next.iter().enumerate()
+            .filter_map(|(idx,pos)| pos.map(|pos| (pos,idx)))
+            .filter(|(pos,idx)| {
+                next.iter().enumerate().all(|(other,other_pos)| {
+                    other == *idx
+                        || other_pos.is_none_or(|other_pos| other_pos > *pos)
+                        || independence.are_independent(&self.letters[other],&self.letters[*idx])
+                })
+            }).collect();
+        enabled.sort();
+        enabled
+    }
+}
+
+/// Checks whether some word equivalent to the given one up to the commutation of adjacent independent letters
+/// is accepted by the NFA.
+/// The search is depth-first and, at each configuration, tries the enabled positions in the order of the word,
+/// hence a word which is accepted as is keeps its order.
+/// At most one configuration per prefix of the partial order and state of the NFA is explored,
+/// which is still exponential in the number of pairwise independent letters.
+pub fn analyze_trace(nfa : &AutNFAIT<usize>,
+                     word : &[usize],
+                     independence : &NfaIndependenceRelation) -> NfaTraceMembership {
+    let occurrences = NfaTraceOccurrences::new(word);
+    let mut prefixes : Vec<NfaTracePrefix> = vec![vec![0;occurrences.letters.len()]];
+    let mut prefix_ids : HashMap<NfaTracePrefix,usize> = HashMap::new();
+    prefix_ids.insert(prefixes[0].clone(),0);
+    let initials : Vec<usize> = get_epsilon_closure(nfa,&nfa.initials.iter().cloned().collect()).into_iter().collect();
+    let mut visited : HashSet<NfaTraceConfiguration> = HashSet::new();
+    let mut parents : HashMap<NfaTraceConfiguration,(NfaTraceConfiguration,usize)> = HashMap::new();
+    let mut stack : Vec<NfaTraceConfiguration> = initials.into_iter().rev().map(|st| (0,st)).collect();
+    while let Some(config) = stack.pop() {
+        if !visited.insert(config) {
+            continue;
+        }
+        let (prefix_id,state) = config;
+        if prefixes[prefix_id].iter().sum::<usize>() == word.len() && nfa.finals.contains(&state) {
+            // backtracking
+            let mut linearization = vec![];
+            let mut current = config;
+            while let Some((parent,pos)) = parents.get(&current) {
+                linearization.push(*pos);
+                current = *parent;
+            }
+            linearization.reverse();
+            return NfaTraceMembership::new(Some(linearization),visited.len());
+        }
+        let mut successors = vec![];
+        for (pos,idx) in occurrences.get_enabled_letters(independence,&prefixes[prefix_id]) {
+            if let Some(targets) = nfa.transitions[state].get(&word[pos]) {
+                let mut new_prefix = prefixes[prefix_id].clone();
+                new_prefix[idx] += 1;
+                let new_prefix_id = match prefix_ids.get(&new_prefix) {
+                    Some(got) => {
+                        *got
+                    },
+                    None => {
+                        let new_id = prefixes.len();
+                        prefix_ids.insert(new_prefix.clone(),new_id);
+                        prefixes.push(new_prefix);
+                        new_id
+                    }
+                };
+                let reached : BTreeSet<usize> = targets.iter()
+                    .flat_map(|target| get_epsilon_closure(nfa,&btreeset!{*target}))
+                    .collect();
+                for target in reached {
+                    successors.push(((new_prefix_id,target),pos));
+                }
+            }
+        }
+        // the successors are pushed in reverse so that earlier positions are explored first
+        for (successor,pos) in successors.into_iter().rev() {
+            if !visited.contains(&successor) {
+                parents.entry(successor).or_insert((config,pos));
+                stack.push(successor);
+            }
+        }
+    }
+    NfaTraceMembership::new(None,visited.len())
+}
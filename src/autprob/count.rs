@@ -0,0 +1,77 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeMap;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::access::AutAccessible;
+
+use crate::autprob::scores::{get_initial_scores, get_next_scores, NfaWeightedScores};
+use crate::autprob::semiring::{NfaRunCountWeight, NfaWeightSemiring};
+use crate::autprob::table::NfaWeightTable;
+
+
+/// Number of runs of the NFA over a word.
+#[derive(Clone, PartialEq, Debug, Eq)]
+pub struct NfaRunCountReport {
+    pub accepting_runs : u64,
+    // number of runs reaching each active state once the word is read
+    pub final_counts : BTreeMap<usize,u64>,
+    // first position after which a co-accessible active state is reached by several runs, if any
+    // (runs which merge in a state from which no final state can be reached cannot both accept)
+    pub first_merge : Option<usize>
+}
+
+impl NfaRunCountReport {
+    pub fn new(accepting_runs: u64, final_counts: BTreeMap<usize, u64>, first_merge: Option<usize>) -> Self {
+        Self { accepting_runs, final_counts, first_merge }
+    }
+
+    /// The word is parsed ambiguously if it is accepted by several runs.
+    pub fn is_ambiguous(&self) -> bool {
+        self.accepting_runs > 1
+    }
+}
+
+/// Counts the runs of the NFA over the word, keeping for each active state the number of runs reaching it.
+/// Several epsilon paths between the same two states count as one.
+pub fn count_runs(nfa : &AutNFAIT<usize>,
+                  word : &[usize]) -> NfaRunCountReport {
+    let table : NfaWeightTable<NfaRunCountWeight> = NfaWeightTable::default();
+    let coaccessible = nfa.get_all_coaccessible_states();
+    let is_merging = |scores : &NfaWeightedScores<NfaRunCountWeight>| {
+        scores.iter().any(|(state,count)| count.0 > 1 && coaccessible.contains(state))
+    };
+    let mut scores = get_initial_scores(nfa);
+    let mut first_merge = None;
+    if is_merging(&scores) {
+        first_merge = Some(0);
+    }
+    for (pos,letter) in word.iter().enumerate() {
+        let distribution = BTreeMap::from([(*letter,NfaRunCountWeight::one())]);
+        scores = get_next_scores(nfa,&table,&scores,&distribution);
+        if first_merge.is_none() && is_merging(&scores) {
+            first_merge = Some(pos + 1);
+        }
+    }
+    let accepting_runs = scores.iter()
+        .filter(|(state,_)| nfa.finals.contains(state))
+        .fold(NfaRunCountWeight::zero(), |acc,(_,count)| acc.plus(count));
+    NfaRunCountReport::new(accepting_runs.0,
+                           scores.into_iter().map(|(state,count)| (state,count.0)).collect(),
+                           first_merge)
+}
@@ -0,0 +1,103 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use maplit::btreeset;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::node::get_epsilon_closure;
+use crate::autprob::semiring::NfaWeightSemiring;
+use crate::autprob::table::NfaWeightTable;
+
+
+/// Weights of the letters which may have occurred at a given position, e.g. the output of a classifier.
+pub type NfaLetterDistribution<Weight> = BTreeMap<usize,Weight>;
+
+/// Active states mapped to their score. States scoring "zero" are not active.
+pub type NfaWeightedScores<Weight> = BTreeMap<usize,Weight>;
+
+/// Adds the score to that of the state.
+pub(crate) fn add_score<Weight : NfaWeightSemiring>(scores : &mut NfaWeightedScores<Weight>,
+                                                    state : usize,
+                                                    score : Weight) {
+    if score.is_zero() {
+        return;
+    }
+    let updated = match scores.get(&state) {
+        None => {
+            score
+        },
+        Some(previous) => {
+            previous.plus(&score)
+        }
+    };
+    scores.insert(state,updated);
+}
+
+/// Propagates the scores along epsilon transitions.
+/// Each state reachable via epsilon transitions receives the score of its origin once,
+/// however many epsilon paths lead to it.
+pub fn close_scores<Weight : NfaWeightSemiring>(nfa : &AutNFAIT<usize>,
+                                                scores : NfaWeightedScores<Weight>) -> NfaWeightedScores<Weight> {
+    let mut closed = BTreeMap::new();
+    for (state,score) in scores {
+        for target in get_epsilon_closure(nfa,&btreeset!{state}) {
+            add_score(&mut closed,target,score.clone());
+        }
+    }
+    closed
+}
+
+pub fn get_initial_scores<Weight : NfaWeightSemiring>(nfa : &AutNFAIT<usize>) -> NfaWeightedScores<Weight> {
+    let initials : BTreeSet<usize> = nfa.initials.iter().cloned().collect();
+    close_scores(nfa,initials.into_iter().map(|st| (st,Weight::one())).collect())
+}
+
+/// Returns the scores after reading any of the letters of the distribution.
+pub fn get_next_scores<Weight : NfaWeightSemiring>(nfa : &AutNFAIT<usize>,
+                                                   table : &NfaWeightTable<Weight>,
+                                                   scores : &NfaWeightedScores<Weight>,
+                                                   distribution : &NfaLetterDistribution<Weight>) -> NfaWeightedScores<Weight> {
+    let mut next = BTreeMap::new();
+    for (state,score) in scores {
+        for (letter,letter_weight) in distribution {
+            if let Some(targets) = nfa.transitions[*state].get(letter) {
+                for target in targets {
+                    let weight = score.times(letter_weight).times(&table.get_weight(&(*state,*letter,*target)));
+                    add_score(&mut next,*target,weight);
+                }
+            }
+        }
+    }
+    close_scores(nfa,next)
+}
+
+/// Returns the combined score of all the accepting runs over the sequence of distributions
+/// (e.g. the likelihood of the word with probabilities or that of its most likely run with Viterbi weights).
+pub fn get_word_score<Weight : NfaWeightSemiring>(nfa : &AutNFAIT<usize>,
+                                                  table : &NfaWeightTable<Weight>,
+                                                  word : &[NfaLetterDistribution<Weight>]) -> Weight {
+    let mut scores = get_initial_scores(nfa);
+    for distribution in word {
+        scores = get_next_scores(nfa,table,&scores,distribution);
+    }
+    scores.iter()
+        .filter(|(state,_)| nfa.finals.contains(state))
+        .fold(Weight::zero(), |acc,(_,score)| acc.plus(score))
+}
@@ -0,0 +1,126 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt::Debug;
+
+
+/// Weights with which the active states of the NFA are scored.
+/// Alternative runs are combined with "plus" and successive transitions with "times".
+pub trait NfaWeightSemiring : Clone + Debug + PartialEq {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn plus(&self, other : &Self) -> Self;
+    fn times(&self, other : &Self) -> Self;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+/// A semiring in which "plus" selects one of its operands,
+/// so that there is a best run to speak of.
+pub trait NfaSelectiveSemiring : NfaWeightSemiring {
+    fn is_preferred_to(&self, other : &Self) -> bool;
+}
+
+/// Probability of the most likely run (max, times).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NfaViterbiWeight(pub f64);
+
+impl NfaWeightSemiring for NfaViterbiWeight {
+    fn zero() -> Self {
+        NfaViterbiWeight(0.0)
+    }
+    fn one() -> Self {
+        NfaViterbiWeight(1.0)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        NfaViterbiWeight(self.0.max(other.0))
+    }
+    fn times(&self, other: &Self) -> Self {
+        NfaViterbiWeight(self.0 * other.0)
+    }
+}
+
+impl NfaSelectiveSemiring for NfaViterbiWeight {
+    fn is_preferred_to(&self, other: &Self) -> bool {
+        self.0 > other.0
+    }
+}
+
+/// Probability summed over all runs (plus, times).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NfaProbabilityWeight(pub f64);
+
+impl NfaWeightSemiring for NfaProbabilityWeight {
+    fn zero() -> Self {
+        NfaProbabilityWeight(0.0)
+    }
+    fn one() -> Self {
+        NfaProbabilityWeight(1.0)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        NfaProbabilityWeight(self.0 + other.0)
+    }
+    fn times(&self, other: &Self) -> Self {
+        NfaProbabilityWeight(self.0 * other.0)
+    }
+}
+
+/// Cost of the cheapest run (min, plus), e.g. with negative log-probabilities.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NfaTropicalWeight(pub f64);
+
+impl NfaWeightSemiring for NfaTropicalWeight {
+    fn zero() -> Self {
+        NfaTropicalWeight(f64::INFINITY)
+    }
+    fn one() -> Self {
+        NfaTropicalWeight(0.0)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        NfaTropicalWeight(self.0.min(other.0))
+    }
+    fn times(&self, other: &Self) -> Self {
+        NfaTropicalWeight(self.0 + other.0)
+    }
+}
+
+impl NfaSelectiveSemiring for NfaTropicalWeight {
+    fn is_preferred_to(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+/// Number of runs (plus, times) on natural numbers, saturating at the maximal value.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NfaRunCountWeight(pub u64);
+
+impl NfaWeightSemiring for NfaRunCountWeight {
+    fn zero() -> Self {
+        NfaRunCountWeight(0)
+    }
+    fn one() -> Self {
+        NfaRunCountWeight(1)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        NfaRunCountWeight(self.0.saturating_add(other.0))
+    }
+    fn times(&self, other: &Self) -> Self {
+        NfaRunCountWeight(self.0.saturating_mul(other.0))
+    }
+}
@@ -0,0 +1,52 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::HashMap;
+
+use crate::autprob::semiring::NfaWeightSemiring;
+
+
+/// A transition given by its origin state, its letter and its target state.
+pub type NfaWeightedTransition = (usize,usize,usize);
+
+/// Weights of the transitions of an NFA.
+/// Transitions which are not in the table, as well as epsilon transitions, weigh "one".
+#[derive(Clone, PartialEq, Debug)]
+pub struct NfaWeightTable<Weight : NfaWeightSemiring> {
+    pub weights : HashMap<NfaWeightedTransition,Weight>
+}
+
+impl<Weight : NfaWeightSemiring> Default for NfaWeightTable<Weight> {
+    fn default() -> Self {
+        Self { weights : HashMap::new() }
+    }
+}
+
+impl<Weight : NfaWeightSemiring> NfaWeightTable<Weight> {
+    pub fn new(weights: HashMap<NfaWeightedTransition, Weight>) -> Self {
+        Self { weights }
+    }
+
+    pub fn with_weight(mut self, transition : NfaWeightedTransition, weight : Weight) -> Self {
+        self.weights.insert(transition,weight);
+        self
+    }
+
+    pub fn get_weight(&self, transition : &NfaWeightedTransition) -> Weight {
+        self.weights.get(transition).cloned().unwrap_or_else(Weight::one)
+    }
+}
@@ -0,0 +1,127 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeMap;
+
+use maplit::btreeset;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::node::get_epsilon_closure;
+use crate::autprob::scores::NfaLetterDistribution;
+use crate::autprob::semiring::NfaSelectiveSemiring;
+use crate::autprob::table::NfaWeightTable;
+
+
+/// An accepting run of the NFA, given by the state it is in after each letter, alongside its score.
+#[derive(Clone, PartialEq, Debug)]
+pub struct NfaWeightedRun<Weight : NfaSelectiveSemiring> {
+    // the first state is the one the run starts from
+    // epsilon transitions taken in between letters are not listed
+    pub states : Vec<usize>,
+    pub letters : Vec<usize>,
+    pub score : Weight
+}
+
+impl<Weight : NfaSelectiveSemiring> NfaWeightedRun<Weight> {
+    pub fn new(states: Vec<usize>, letters: Vec<usize>, score: Weight) -> Self {
+        Self { states, letters, score }
+    }
+}
+
+/// Best score of each active state alongside the state and letter it comes from at the previous position.
+type NfaViterbiColumn<Weight> = BTreeMap<usize,(Weight,Option<(usize,usize)>)>;
+
+/// Keeps the preferred score of the state (on ties, the first one found).
+fn offer<Weight : NfaSelectiveSemiring>(column : &mut NfaViterbiColumn<Weight>,
+                                        state : usize,
+                                        score : Weight,
+                                        origin : Option<(usize,usize)>) {
+    if score.is_zero() {
+        return;
+    }
+    match column.get(&state) {
+        Some((previous,_)) if !score.is_preferred_to(previous) => {},
+        _ => {
+            column.insert(state,(score,origin));
+        }
+    }
+}
+
+fn close_column<Weight : NfaSelectiveSemiring>(nfa : &AutNFAIT<usize>,
+                                               column : NfaViterbiColumn<Weight>) -> NfaViterbiColumn<Weight> {
+    let mut closed = BTreeMap::new();
+    for (state,(score,origin)) in column {
+        for target in get_epsilon_closure(nfa,&btreeset!{state}) {
+            offer(&mut closed,target,score.clone(),origin);
+        }
+    }
+    closed
+}
+
+/// Returns the best accepting run over the sequence of distributions, if any,
+/// e.g. the most probable one with Viterbi weights.
+pub fn get_best_run<Weight : NfaSelectiveSemiring>(nfa : &AutNFAIT<usize>,
+                                                   table : &NfaWeightTable<Weight>,
+                                                   word : &[NfaLetterDistribution<Weight>]) -> Option<NfaWeightedRun<Weight>> {
+    let mut initials : Vec<usize> = nfa.initials.iter().cloned().collect();
+    initials.sort();
+    let mut init_column = BTreeMap::new();
+    for state in initials {
+        offer(&mut init_column,state,Weight::one(),None);
+    }
+    let mut columns = vec![close_column(nfa,init_column)];
+    for distribution in word {
+        let mut next = BTreeMap::new();
+        for (state,(score,_)) in columns.last().unwrap() {
+            for (letter,letter_weight) in distribution {
+                if let Some(targets) = nfa.transitions[*state].get(letter) {
+                    let mut targets : Vec<&usize> = targets.iter().collect();
+                    targets.sort();
+                    for target in targets {
+                        let weight = score.times(letter_weight).times(&table.get_weight(&(*state,*letter,*target)));
+                        offer(&mut next,*target,weight,Some((*state,*letter)));
+                    }
+                }
+            }
+        }
+        columns.push(close_column(nfa,next));
+    }
+    // the best final state of the last column
+    let mut best : Option<(usize,&Weight)> = None;
+    for (state,(score,_)) in columns.last().unwrap() {
+        if nfa.finals.contains(state) && best.is_none_or(|(_,got)| score.is_preferred_to(got)) {
+            best = Some((*state,score));
+        }
+    }
+    let (last_state,score) = best?;
+    let score = score.clone();
+    // backtracking
+    let mut states = vec![last_state];
+    let mut letters = vec![];
+    let mut current = last_state;
+    for column in columns.iter().skip(1).rev() {
+        let (_,origin) = column.get(&current).unwrap();
+        let (previous,letter) = origin.unwrap();
+        states.push(previous);
+        letters.push(letter);
+        current = previous;
+    }
+    states.reverse();
+    letters.reverse();
+    Some(NfaWeightedRun::new(states,letters,score))
+}
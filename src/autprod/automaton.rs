@@ -0,0 +1,101 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeSet, HashSet};
+
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::translate::AutTranslatable;
+
+use crate::autana::node::{get_epsilon_closure, get_successors};
+use crate::autana::param::NfaWordAnalysisResetOn;
+
+
+/// The synchronized product of several component NFAs.
+/// A letter is read synchronously by all the components having it in their alphabet while the others do not move.
+/// The product is never built: its states are explored on the fly as tuples of sets of active states.
+pub struct NfaProductAutomaton {
+    pub components : Vec<AutNFAIT<usize>>,
+    pub alphabets : Vec<HashSet<usize>>
+}
+
+impl NfaProductAutomaton {
+    /// Each component synchronizes on the letters of its own alphabet.
+    pub fn new(components: Vec<AutNFA<usize>>) -> Self {
+        let alphabets = components.iter().map(|nfa| nfa.alphabet.clone()).collect();
+        let components = components.into_iter().map(|nfa| nfa.to_nfait()).collect();
+        Self { components, alphabets }
+    }
+
+    /// The components may contain epsilon transitions.
+    pub fn from_nfaits(components: Vec<AutNFAIT<usize>>,
+                       alphabets: Vec<HashSet<usize>>) -> Self {
+        Self { components, alphabets }
+    }
+
+    /// Returns the indices of the components which have the letter in their alphabet.
+    pub fn get_involved_components(&self, letter : &usize) -> Vec<usize> {
+        (0..self.components.len()).filter(|idx| self.alphabets[*idx].contains(letter)).collect()
+    }
+
+    /// Returns the active states after reading the letter, if all the components involved can read it.
+    pub fn get_successors(&self,
+                          active_states : &[BTreeSet<usize>],
+                          letter : &usize) -> Option<Vec<BTreeSet<usize>>> {
+        let involved = self.get_involved_components(letter);
+        if involved.is_empty() {
+            return None;
+        }
+        let mut successors = active_states.to_vec();
+        for idx in involved {
+            let reached = get_successors(&self.components[idx],&active_states[idx],letter);
+            if reached.is_empty() {
+                return None;
+            }
+            successors[idx] = reached;
+        }
+        Some(successors)
+    }
+
+    /// Resets each component on its own reset states,
+    /// computed w.r.t. the projection of the word on its alphabet.
+    pub fn get_reset_states(&self,
+                            reset : &NfaWordAnalysisResetOn,
+                            history : &[usize],
+                            upcoming : &[usize]) -> Vec<BTreeSet<usize>> {
+        self.components.iter().zip(self.alphabets.iter())
+            .map(|(nfa,alphabet)| {
+                // only the last (resp. first) letters of the projections matter
+                let (lookbehind,lookahead) = reset.get_lookaround_lengths();
+                let mut history : Vec<usize> = history.iter().rev()
+                    .filter(|l| alphabet.contains(l))
+                    .take(lookbehind)
+                    .cloned().collect();
+                history.reverse();
+                let upcoming : Vec<usize> = upcoming.iter()
+                    .filter(|l| alphabet.contains(l))
+                    .take(lookahead)
+                    .cloned().collect();
+                get_epsilon_closure(nfa,&reset.get_reset_states(nfa,&history,&upcoming))
+            }).collect()
+    }
+
+    /// The product accepts if every component has an active final state.
+    pub fn is_accepting(&self, active_states : &[BTreeSet<usize>]) -> bool {
+        self.components.iter().zip(active_states.iter())
+            .all(|(nfa,states)| states.iter().any(|st| nfa.finals.contains(st)))
+    }
+}
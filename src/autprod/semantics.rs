@@ -0,0 +1,103 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Formatter;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autana::param::NfaWordAnalysisResetOn;
+use crate::autconf::conf::NfaConfigurationAnalysisConfig;
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autprod::automaton::NfaProductAutomaton;
+
+
+/// A product has no deviation of its own:
+/// a letter either is read synchronously by all the components involved or is not read at all.
+#[derive(Clone, PartialEq, Debug, Eq, PartialOrd, Ord, Hash)]
+pub enum NfaProductViolation {}
+
+impl fmt::Display for NfaProductViolation {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+pub type NfaProductAnalysisContext<Printer> = NfaConfigurationAnalysisContext<NfaProductAutomaton,Printer>;
+
+pub type NfaProductAnalysisConfig<Printer> = NfaConfigurationAnalysisConfig<NfaProductAutomaton,Printer>;
+
+/// Configurations are tuples of the sets of active states of the components.
+impl NfaConfigurationSemantics for NfaProductAutomaton {
+    type Event = usize;
+    type Configurations = Vec<BTreeSet<usize>>;
+    type Violation = NfaProductViolation;
+
+    fn get_letter(event : &usize) -> usize {
+        *event
+    }
+
+    /// A letter is known if it is in the alphabet of some component.
+    fn is_known_letter(&self, letter : &usize) -> bool {
+        !self.get_involved_components(letter).is_empty()
+    }
+
+    fn get_reset_configurations(&self,
+                                reset : &NfaWordAnalysisResetOn,
+                                history : &[usize],
+                                upcoming : &[usize]) -> Vec<BTreeSet<usize>> {
+        self.get_reset_states(reset,history,upcoming)
+    }
+
+    fn read_event(&self,
+                  configurations : &Vec<BTreeSet<usize>>,
+                  word : &[usize],
+                  pos_in_trace : usize) -> Result<Vec<BTreeSet<usize>>,Option<NfaProductViolation>> {
+        self.get_successors(configurations,&word[pos_in_trace]).ok_or(None)
+    }
+
+    /// Inclusion is checked component-wise.
+    fn is_included(&self,
+                   configurations : &Vec<BTreeSet<usize>>,
+                   others : &Vec<BTreeSet<usize>>) -> bool {
+        configurations.iter().zip(others.iter()).all(|(states,other)| states.is_subset(other))
+    }
+
+    fn is_accepting(&self, configurations : &Vec<BTreeSet<usize>>) -> bool {
+        self.is_accepting(configurations)
+    }
+
+    fn configurations_to_string(&self,
+                                configurations : &Vec<BTreeSet<usize>>,
+                                _word : &[usize],
+                                _pos_in_trace : usize) -> String {
+        let components : Vec<String> = configurations.iter()
+            .map(|states| {
+                let as_strings : Vec<String> = states.iter().map(|st| format!("S{}",st)).collect();
+                format!("{{{}}}",as_strings.join(","))
+            }).collect();
+        format!("({})",components.join(","))
+    }
+
+    fn event_to_string<Printer : AbstractLanguagePrinter<usize>>(&self,
+                                                                 word : &[usize],
+                                                                 pos_in_trace : usize,
+                                                                 printer : &Printer) -> String {
+        printer.get_letter_string_repr(&word[pos_in_trace])
+    }
+}
@@ -0,0 +1,54 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+
+use crate::autrep::context::NfaWordRepairContext;
+use crate::autrep::filter::elim::NfaWordRepairFilterEliminationKind;
+use crate::autrep::filter::filter::NfaWordRepairFilterCriterion;
+use crate::autrep::handling::handler::NfaWordRepairProcessHandler;
+use crate::autrep::node::NfaWordRepairNodeKind;
+use crate::autrep::param::NfaWordRepairParameterization;
+use crate::autrep::priorities::NfaWordRepairPriorities;
+use crate::autrep::step::NfaWordRepairStepKind;
+use crate::autrep::verdict::global::NfaWordRepairGlobalVerdict;
+use crate::autrep::verdict::local::NfaWordRepairLocalVerdict;
+
+
+
+pub struct NfaWordRepairConfig<Printer : AbstractLanguagePrinter<usize>> {
+    phantom : std::marker::PhantomData<Printer>
+}
+
+pub struct NfaWordRepairStaticLocalVerdictAnalysisProof{}
+
+impl<Printer : AbstractLanguagePrinter<usize>> AbstractProcessConfiguration for NfaWordRepairConfig<Printer> {
+    type Context = NfaWordRepairContext<Printer>;
+    type Parameterization = NfaWordRepairParameterization;
+    type NodeKind = NfaWordRepairNodeKind;
+    type StepKind = NfaWordRepairStepKind;
+    type Priorities = NfaWordRepairPriorities;
+    type FilterCriterion = NfaWordRepairFilterCriterion;
+    type FilterEliminationKind = NfaWordRepairFilterEliminationKind;
+    type LocalVerdict = NfaWordRepairLocalVerdict;
+    type StaticLocalVerdictAnalysisProof = NfaWordRepairStaticLocalVerdictAnalysisProof;
+    type GlobalVerdict = NfaWordRepairGlobalVerdict;
+    type ProcessHandler = NfaWordRepairProcessHandler;
+}
@@ -0,0 +1,39 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use autour_core::nfa::nfa::AutNFA;
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+use autour_core::traits::translate::AutTranslatable;
+
+
+pub struct NfaWordRepairContext<Printer : AbstractLanguagePrinter<usize>> {
+    pub nfa : AutNFAIT<usize>,
+    pub printer : Printer,
+    pub word : Vec<usize>
+}
+
+impl<Printer: AbstractLanguagePrinter<usize>> NfaWordRepairContext<Printer> {
+    pub fn new(nfa: AutNFA<usize>, printer: Printer, word: Vec<usize>) -> Self {
+        Self { nfa : nfa.to_nfait(), printer, word }
+    }
+
+    /// The automaton may contain epsilon transitions.
+    pub fn from_nfait(nfa: AutNFAIT<usize>, printer: Printer, word: Vec<usize>) -> Self {
+        Self { nfa, printer, word }
+    }
+}
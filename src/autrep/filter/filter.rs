@@ -0,0 +1,68 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fmt;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use crate::autrep::filter::elim::NfaWordRepairFilterEliminationKind;
+
+
+pub struct NfaWordRepairFilterCriterion {
+    // cost of the edits leading to the new node
+    pub cost : u32
+}
+
+impl fmt::Display for NfaWordRepairFilterCriterion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"cost={:}",self.cost)
+    }
+}
+
+pub enum NfaWordRepairFilter {
+    MaxCost(u32)
+}
+
+
+impl fmt::Display for NfaWordRepairFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaWordRepairFilter::MaxCost(max) => {
+                write!(f,"MaxCost={:}",max)
+            }
+        }
+    }
+}
+
+impl AbstractFilter<NfaWordRepairFilterCriterion,NfaWordRepairFilterEliminationKind>  for NfaWordRepairFilter {
+
+    fn apply_filter(&self,
+                    _depth: u32,
+                    _node_counter: u32,
+                    criterion: &NfaWordRepairFilterCriterion) -> Option<NfaWordRepairFilterEliminationKind> {
+        match self {
+            NfaWordRepairFilter::MaxCost(max) => {
+                if criterion.cost > *max {
+                    Some(NfaWordRepairFilterEliminationKind::MaxCost)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+}
+
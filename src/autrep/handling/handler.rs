@@ -0,0 +1,146 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use graph_process_manager_core::delegate::node::GenericNode;
+use graph_process_manager_core::handler::handler::AbstractProcessHandler;
+use graph_process_manager_core::queued_steps::step::GenericStep;
+
+use crate::autana::node::get_successors;
+use crate::autrep::conf::{NfaWordRepairConfig, NfaWordRepairStaticLocalVerdictAnalysisProof};
+use crate::autrep::context::NfaWordRepairContext;
+use crate::autrep::filter::filter::NfaWordRepairFilterCriterion;
+use crate::autrep::node::NfaWordRepairNodeKind;
+use crate::autrep::param::NfaWordRepairParameterization;
+use crate::autrep::step::NfaWordRepairStepKind;
+use crate::autrep::verdict::local::NfaWordRepairLocalVerdict;
+
+
+/// Explores the graph of edits so that it can be drawn,
+/// in the order of the search strategy and of the priorities with which the process is run.
+/// This graph is infinite as soon as the NFA contains cycles (insertions can loop):
+/// the process must hence be run with memoization (a node reached with more edits than
+/// an already known node in the same configuration is not explored further) and/or with a
+/// MaxCost filter.
+/// No strategy orders the exploration by cumulative cost, hence the first repair it finds may not be minimal:
+/// `crate::autrep::search::get_minimal_repair` is the supported entry point to compute a repair of minimal cost.
+pub struct NfaWordRepairProcessHandler {}
+
+impl<Printer : AbstractLanguagePrinter<usize>>
+    AbstractProcessHandler<NfaWordRepairConfig<Printer>> for NfaWordRepairProcessHandler {
+
+    fn process_new_step(_context: &NfaWordRepairContext<Printer>,
+                        param : &NfaWordRepairParameterization,
+                        parent_state: &GenericNode<NfaWordRepairNodeKind>,
+                        step_to_process: &GenericStep<NfaWordRepairStepKind>,
+                        _new_state_id: u32,
+                        _node_counter: u32) -> NfaWordRepairNodeKind {
+        let new_cost = parent_state.kind.cost.saturating_add(param.costs.get_step_cost(&step_to_process.kind));
+        let parent_pos = parent_state.kind.pos_in_trace;
+        match &step_to_process.kind {
+            NfaWordRepairStepKind::Read(new_active) |
+            NfaWordRepairStepKind::Substitute(_,new_active) => {
+                NfaWordRepairNodeKind::new(new_active.clone(),parent_pos + 1,new_cost)
+            },
+            NfaWordRepairStepKind::Insert(_,new_active) => {
+                NfaWordRepairNodeKind::new(new_active.clone(),parent_pos,new_cost)
+            },
+            NfaWordRepairStepKind::Delete => {
+                NfaWordRepairNodeKind::new(parent_state.kind.active_states.clone(),parent_pos + 1,new_cost)
+            }
+        }
+    }
+
+    fn get_criterion(_context: &NfaWordRepairContext<Printer>,
+                     param : &NfaWordRepairParameterization,
+                     parent_state: &GenericNode<NfaWordRepairNodeKind>,
+                     step_to_process: &GenericStep<NfaWordRepairStepKind>,
+                     _new_state_id: u32,
+                     _node_counter: u32) -> NfaWordRepairFilterCriterion {
+        let cost = parent_state.kind.cost.saturating_add(param.costs.get_step_cost(&step_to_process.kind));
+        NfaWordRepairFilterCriterion{cost}
+    }
+
+    fn collect_next_steps(context: &NfaWordRepairContext<Printer>,
+                          _param : &NfaWordRepairParameterization,
+                          parent_node_kind: &NfaWordRepairNodeKind)
+                -> Vec<NfaWordRepairStepKind> {
+        get_repair_steps(&context.nfa,&context.word,parent_node_kind)
+    }
+
+    fn get_local_verdict_when_no_child(context: &NfaWordRepairContext<Printer>,
+                                       _param : &NfaWordRepairParameterization,
+                                       node_kind: &NfaWordRepairNodeKind) -> NfaWordRepairLocalVerdict {
+        if context.word.get(node_kind.pos_in_trace).is_none() && is_accepting(&context.nfa,node_kind) {
+            NfaWordRepairLocalVerdict::Repaired(node_kind.cost)
+        } else {
+            NfaWordRepairLocalVerdict::DeadEnd
+        }
+    }
+
+    fn get_local_verdict_from_static_analysis(_context: &NfaWordRepairContext<Printer>,
+                                              _param : &NfaWordRepairParameterization,
+                                              _node_kind: &mut NfaWordRepairNodeKind)
+            -> Option<(NfaWordRepairLocalVerdict,NfaWordRepairStaticLocalVerdictAnalysisProof)> {
+        None
+    }
+
+    fn pursue_process_after_static_verdict(_context: &NfaWordRepairContext<Printer>,
+                                           _param : &NfaWordRepairParameterization,
+                                           _loc_verd: &NfaWordRepairLocalVerdict) -> bool {
+        true
+    }
+}
+
+
+/// Returns the edits which can be applied from a node, in the same order for the process and for
+/// the cost-ordered search of a minimal repair.
+pub(crate) fn get_repair_steps(nfa : &AutNFAIT<usize>,
+                               word : &[usize],
+                               node_kind : &NfaWordRepairNodeKind) -> Vec<NfaWordRepairStepKind> {
+    let next_letter = word.get(node_kind.pos_in_trace);
+    if next_letter.is_none() && is_accepting(nfa,node_kind) {
+        // the word is repaired, there is no need to edit it further
+        return vec![];
+    }
+    // only the letters which can be read from the active states are worth inserting or substituting
+    let enabled : BTreeSet<usize> = node_kind.active_states.iter()
+        .flat_map(|st| nfa.transitions[*st].keys().cloned())
+        .collect();
+    let mut steps = vec![];
+    if let Some(letter) = next_letter {
+        if enabled.contains(letter) {
+            steps.push(NfaWordRepairStepKind::Read(get_successors(nfa,&node_kind.active_states,letter)));
+        }
+        steps.push(NfaWordRepairStepKind::Delete);
+        for other in enabled.iter().filter(|x| *x != letter) {
+            steps.push(NfaWordRepairStepKind::Substitute(*other,get_successors(nfa,&node_kind.active_states,other)));
+        }
+    }
+    for inserted in &enabled {
+        steps.push(NfaWordRepairStepKind::Insert(*inserted,get_successors(nfa,&node_kind.active_states,inserted)));
+    }
+    steps
+}
+
+pub(crate) fn is_accepting(nfa : &AutNFAIT<usize>,
+                           node_kind : &NfaWordRepairNodeKind) -> bool {
+    node_kind.active_states.iter().any(|st| nfa.finals.contains(st))
+}
@@ -0,0 +1,150 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use graph_process_manager_loggers::graphviz::drawer::GraphVizProcessDrawer;
+use graph_process_manager_loggers::graphviz::format::GraphVizLoggerNodeFormat;
+use graphviz_dot_builder::colors::GraphvizColor;
+use graphviz_dot_builder::item::cluster::GraphVizCluster;
+use graphviz_dot_builder::item::node::node::GraphVizNode;
+use graphviz_dot_builder::item::node::style::{GraphvizNodeStyleItem, GvNodeShape};
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autrep::conf::{NfaWordRepairConfig, NfaWordRepairStaticLocalVerdictAnalysisProof};
+use crate::autrep::context::NfaWordRepairContext;
+use crate::autrep::node::NfaWordRepairNodeKind;
+use crate::autrep::param::NfaWordRepairParameterization;
+use crate::autrep::step::NfaWordRepairStepKind;
+use crate::autrep::verdict::local::NfaWordRepairLocalVerdict;
+
+
+pub struct NfaWordRepairProcessDrawer {
+    pub temp_folder : String
+}
+
+impl NfaWordRepairProcessDrawer {
+    pub fn new(temp_folder: String) -> Self {
+        Self { temp_folder }
+    }
+}
+
+impl<Printer : AbstractLanguagePrinter<usize>>
+        GraphVizProcessDrawer<NfaWordRepairConfig<Printer>> for NfaWordRepairProcessDrawer {
+
+    fn repr_static_analysis(&self) -> bool {
+        false
+    }
+
+    fn get_temp_folder(&self) -> &str {
+        &self.temp_folder
+    }
+
+    fn get_verdict_color(&self,
+                         local_verdict: &NfaWordRepairLocalVerdict) -> GraphvizColor {
+        match local_verdict {
+            NfaWordRepairLocalVerdict::Repaired(_) => {
+                GraphvizColor::green
+            },
+            NfaWordRepairLocalVerdict::DeadEnd => {
+                GraphvizColor::red
+            }
+        }
+    }
+
+    fn make_static_analysis_as_gvcluster(&self,
+                                         _context: &NfaWordRepairContext<Printer>,
+                                         _param : &NfaWordRepairParameterization,
+                                         _parent_state_id: u32,
+                                         _verdict: &NfaWordRepairLocalVerdict,
+                                         _data_proof: &NfaWordRepairStaticLocalVerdictAnalysisProof) -> GraphVizCluster {
+        panic!("should not be called")
+    }
+
+    fn make_step_gvnode(&self,
+                        context: &NfaWordRepairContext<Printer>,
+                        _param : &NfaWordRepairParameterization,
+                        origin_state_id: u32,
+                        target_state_id: u32,
+                        step: &NfaWordRepairStepKind) -> GraphVizNode {
+        let label = match step {
+            NfaWordRepairStepKind::Insert(letter,_) |
+            NfaWordRepairStepKind::Substitute(letter,_) => {
+                format!("{} {}",step,context.printer.get_letter_string_repr(letter))
+            },
+            _ => {
+                step.to_string()
+            }
+        };
+        let style = vec![
+            GraphvizNodeStyleItem::Label(label),
+            GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle)
+        ];
+        GraphVizNode::new(format!("s_{}_{}", origin_state_id, target_state_id), style)
+    }
+
+    fn make_node_gvitem_as_gvcluster(&self,
+                                     _context: &NfaWordRepairContext<Printer>,
+                                     _parameterization: &NfaWordRepairParameterization,
+                                     _new_state_id: u32,
+                                     _new_node: &NfaWordRepairNodeKind) -> GraphVizCluster {
+        panic!("should not be called")
+    }
+
+    fn make_node_gvitem_as_gvnode(&self,
+                                  context: &NfaWordRepairContext<Printer>,
+                                  _parameterization: &NfaWordRepairParameterization,
+                                  new_state_id: u32,
+                                  new_node: &NfaWordRepairNodeKind) -> GraphVizNode {
+        let states : Vec<String> = new_node.active_states.iter().map(|st| format!("S{}",st)).collect();
+        let remaining = if new_node.pos_in_trace < context.word.len() {
+            let as_letters : Vec<String> = context.word[new_node.pos_in_trace..]
+                .iter().map(|l| context.printer.get_letter_string_repr(l)).collect();
+            as_letters.join(context.printer.get_concatenation_separator(false))
+        } else {
+            context.printer.get_epsilon_symbol(false).to_string()
+        };
+        let label = format!("{{{}}} <- {} (cost={})", states.join(","), remaining, new_node.cost);
+        let style = vec![
+            GraphvizNodeStyleItem::Label(label),
+            GraphvizNodeStyleItem::FillColor( GraphvizColor::white ),
+            GraphvizNodeStyleItem::Shape(GvNodeShape::Rectangle)
+        ];
+        let node_id = <NfaWordRepairProcessDrawer as GraphVizProcessDrawer<NfaWordRepairConfig<Printer>>>::get_node_id(self, new_state_id);
+        GraphVizNode::new(node_id, style)
+    }
+
+    fn get_node_format(&self) -> &GraphVizLoggerNodeFormat {
+        &GraphVizLoggerNodeFormat::SimpleNode
+    }
+
+    fn get_anchor_id(&self, id: u32) -> String {
+        format!("n{}", id)
+    }
+
+    fn get_node_id(&self, id: u32) -> String {
+        format!("n{}", id)
+    }
+
+    fn get_verdict_id(&self, id: u32) -> String {
+        format!("v{}", id)
+    }
+
+    fn get_static_analysis_ids(&self, _id: u32) -> (String, String) {
+        panic!()
+    }
+
+}
@@ -0,0 +1,176 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::any::Any;
+use std::collections::HashMap;
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::autrep::conf::{NfaWordRepairConfig, NfaWordRepairStaticLocalVerdictAnalysisProof};
+use crate::autrep::context::NfaWordRepairContext;
+use crate::autrep::filter::elim::NfaWordRepairFilterEliminationKind;
+use crate::autrep::filter::filter::NfaWordRepairFilterCriterion;
+use crate::autrep::loggers::rlog::repair::NfaWordRepair;
+use crate::autrep::param::NfaWordRepairParameterization;
+use crate::autrep::node::NfaWordRepairNodeKind;
+use crate::autrep::priorities::NfaWordRepairPriorities;
+use crate::autrep::step::NfaWordRepairStepKind;
+use crate::autrep::verdict::global::NfaWordRepairGlobalVerdict;
+use crate::autrep::verdict::local::NfaWordRepairLocalVerdict;
+
+
+/// Keeps track of the repairs found by the process so that they can be inspected once it terminates.
+pub struct NfaWordRepairsLogger {
+    // maps node ids to the id of the node from which they were first reached and the corresponding step
+    parents : HashMap<u32,(u32,NfaWordRepairStepKind)>,
+    // nodes at which the word is repaired and the corresponding costs
+    repaired : Vec<(u32,u32)>,
+    // repairs built on termination, ordered by increasing cost
+    pub repairs : Vec<NfaWordRepair>
+}
+
+impl NfaWordRepairsLogger {
+    pub fn new() -> Self {
+        Self { parents : HashMap::new(),
+            repaired : vec![],
+            repairs : vec![] }
+    }
+
+    /// Returns a repair of minimal cost, if any.
+    pub fn get_best_repair(&self) -> Option<&NfaWordRepair> {
+        self.repairs.first()
+    }
+
+    fn make_repair(&self,
+                   terminal_id : u32,
+                   cost : u32) -> NfaWordRepair {
+        let mut steps = vec![];
+        let mut current_id = terminal_id;
+        while let Some((parent_id,step)) = self.parents.get(&current_id) {
+            steps.push(step.clone());
+            current_id = *parent_id;
+        }
+        steps.reverse();
+        NfaWordRepair::new(cost,steps)
+    }
+}
+
+impl Default for NfaWordRepairsLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Printer : AbstractLanguagePrinter<usize> + 'static>
+        AbstractProcessLogger<NfaWordRepairConfig<Printer>> for NfaWordRepairsLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.parents.clear();
+        self.repaired.clear();
+        self.repairs.clear();
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<NfaWordRepairPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<NfaWordRepairFilterCriterion, NfaWordRepairFilterEliminationKind>>],
+                            _goal: &Option<NfaWordRepairGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &NfaWordRepairParameterization) {
+        // nothing
+    }
+
+    fn log_filtered(&mut self,
+                    _context: &NfaWordRepairContext<Printer>,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &NfaWordRepairFilterEliminationKind) {
+        // nothing
+    }
+
+    fn log_new_node(&mut self,
+                    _context: &NfaWordRepairContext<Printer>,
+                    _param: &NfaWordRepairParameterization,
+                    _new_node_id: u32,
+                    _new_node: &NfaWordRepairNodeKind) {
+        // nothing
+    }
+
+    fn log_new_step(&mut self,
+                    _context: &NfaWordRepairContext<Printer>,
+                    _param: &NfaWordRepairParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &NfaWordRepairStepKind,
+                    _target_node: &NfaWordRepairNodeKind,
+                    _target_depth: u32) {
+        // with memoization, steps may lead to an already known node which was reached with less edits
+        // only the step which created the node is kept (nodes are created with increasing ids)
+        if origin_node_id < target_node_id {
+            self.parents.entry(target_node_id).or_insert((origin_node_id,step.clone()));
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &NfaWordRepairContext<Printer>,
+                               _param: &NfaWordRepairParameterization,
+                               parent_node_id: u32,
+                               verdict: &NfaWordRepairLocalVerdict) {
+        if let NfaWordRepairLocalVerdict::Repaired(cost) = verdict {
+            self.repaired.push((parent_node_id,*cost));
+        }
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &NfaWordRepairContext<Printer>,
+                                      _param: &NfaWordRepairParameterization,
+                                      _parent_node_id: u32,
+                                      _verdict: &NfaWordRepairLocalVerdict,
+                                      _proof: &NfaWordRepairStaticLocalVerdictAnalysisProof) {
+        // nothing
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &NfaWordRepairGlobalVerdict) {
+        let mut repairs : Vec<NfaWordRepair> = self.repaired.iter()
+            .map(|(terminal_id,cost)| self.make_repair(*terminal_id,*cost))
+            .collect();
+        repairs.sort_by_key(|repair| repair.cost);
+        self.repairs = repairs;
+    }
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &NfaWordRepairContext<Printer>,
+                                        _node_id: u32) {
+        // nothing
+    }
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &NfaWordRepairContext<Printer>,
+                                               _parent_node_id: u32) {
+        // nothing
+    }
+}
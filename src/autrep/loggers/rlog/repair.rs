@@ -0,0 +1,91 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+use crate::autrep::step::NfaWordRepairStepKind;
+
+
+/// A sequence of edits which turns the analyzed word into a word accepted by the NFA.
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NfaWordRepair {
+    pub cost : u32,
+    // the steps from the initial node to the node where the repaired word is accepted
+    pub steps : Vec<NfaWordRepairStepKind>
+}
+
+impl NfaWordRepair {
+    pub fn new(cost: u32, steps: Vec<NfaWordRepairStepKind>) -> Self {
+        Self { cost, steps }
+    }
+
+    /// Returns the steps which are actual edits, i.e. excluding synchronous reads.
+    pub fn get_edits(&self) -> Vec<&NfaWordRepairStepKind> {
+        self.steps.iter()
+            .filter(|step| !matches!(step, NfaWordRepairStepKind::Read(_)))
+            .collect()
+    }
+
+    /// Returns the alignment of the original word (first component) with the repaired word (second component).
+    /// A None stands for a gap i.e. an inserted letter in the first component or a deleted letter in the second.
+    pub fn get_alignment(&self, word : &[usize]) -> Vec<(Option<usize>,Option<usize>)> {
+        let mut pos = 0;
+        let mut alignment = vec![];
+        for step in &self.steps {
+            match step {
+                NfaWordRepairStepKind::Read(_) => {
+                    alignment.push((Some(word[pos]),Some(word[pos])));
+                    pos += 1;
+                },
+                NfaWordRepairStepKind::Insert(letter,_) => {
+                    alignment.push((None,Some(*letter)));
+                },
+                NfaWordRepairStepKind::Delete => {
+                    alignment.push((Some(word[pos]),None));
+                    pos += 1;
+                },
+                NfaWordRepairStepKind::Substitute(letter,_) => {
+                    alignment.push((Some(word[pos]),Some(*letter)));
+                    pos += 1;
+                }
+            }
+        }
+        alignment
+    }
+
+    pub fn get_repaired_word(&self, word : &[usize]) -> Vec<usize> {
+        self.get_alignment(word).into_iter()
+            .filter_map(|(_,repaired)| repaired)
+            .collect()
+    }
+
+    /// Prints the alignment as two rows, the original word above the repaired word, with '-' for gaps.
+    pub fn alignment_to_string<Printer : AbstractLanguagePrinter<usize>>(&self,
+                                                                         word : &[usize],
+                                                                         printer : &Printer) -> String {
+        let mut top = vec![];
+        let mut bottom = vec![];
+        for (original,repaired) in self.get_alignment(word) {
+            let original = original.map_or("-".to_string(), |l| printer.get_letter_string_repr(&l));
+            let repaired = repaired.map_or("-".to_string(), |l| printer.get_letter_string_repr(&l));
+            let width = original.chars().count().max(repaired.chars().count());
+            top.push(format!("{:<width$}",original,width=width));
+            bottom.push(format!("{:<width$}",repaired,width=width));
+        }
+        format!("{}\n{}",top.join(" ").trim_end(),bottom.join(" ").trim_end())
+    }
+}
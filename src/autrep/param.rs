@@ -0,0 +1,99 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use core::fmt;
+use std::fmt::Formatter;
+
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::letter::AutLetter;
+
+use graph_process_manager_core::manager::config::AbstractProcessParameterization;
+
+use crate::autana::node::get_epsilon_closure;
+use crate::autrep::node::NfaWordRepairNodeKind;
+use crate::autrep::step::NfaWordRepairStepKind;
+
+
+#[derive(Debug, Clone)]
+pub struct NfaWordRepairCosts {
+    pub insertion : u32,
+    pub deletion : u32,
+    pub substitution : u32
+}
+
+impl NfaWordRepairCosts {
+    pub fn new(insertion: u32, deletion: u32, substitution: u32) -> Self {
+        Self { insertion, deletion, substitution }
+    }
+
+    pub fn get_step_cost(&self, step : &NfaWordRepairStepKind) -> u32 {
+        match step {
+            NfaWordRepairStepKind::Read(_) => {
+                0
+            },
+            NfaWordRepairStepKind::Insert(_,_) => {
+                self.insertion
+            },
+            NfaWordRepairStepKind::Delete => {
+                self.deletion
+            },
+            NfaWordRepairStepKind::Substitute(_,_) => {
+                self.substitution
+            }
+        }
+    }
+}
+
+impl Default for NfaWordRepairCosts {
+    fn default() -> Self {
+        NfaWordRepairCosts::new(1,1,1)
+    }
+}
+
+impl fmt::Display for NfaWordRepairCosts {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f,"[insertion={:},deletion={:},substitution={:}]",
+               self.insertion,
+               self.deletion,
+               self.substitution)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NfaWordRepairParameterization {
+    pub costs : NfaWordRepairCosts
+}
+
+impl NfaWordRepairParameterization {
+    pub fn new(costs: NfaWordRepairCosts) -> Self {
+        Self { costs }
+    }
+    pub fn make_init_node<Letter : AutLetter>(&self, nfa : &AutNFAIT<Letter>) -> NfaWordRepairNodeKind {
+        let initials = nfa.initials.iter().cloned().collect();
+        NfaWordRepairNodeKind::new(get_epsilon_closure(nfa,&initials),0,0)
+    }
+}
+
+
+impl AbstractProcessParameterization for NfaWordRepairParameterization {
+    fn get_param_as_strings(&self) -> Vec<String> {
+        vec!["process = NFA word repair".to_string(),
+             format!("edit costs = {:}", self.costs)]
+    }
+}
+
@@ -0,0 +1,85 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+
+use std::fmt;
+use graph_process_manager_core::delegate::priorities::AbstractPriorities;
+use crate::autrep::param::NfaWordRepairCosts;
+use crate::autrep::step::NfaWordRepairStepKind;
+
+
+pub struct NfaWordRepairPriorities {
+    pub read : i32,
+    pub insertion : i32,
+    pub deletion : i32,
+    pub substitution : i32
+}
+
+impl NfaWordRepairPriorities {
+    pub fn new(read: i32, insertion: i32, deletion: i32, substitution: i32) -> Self {
+        Self { read, insertion, deletion, substitution }
+    }
+
+    /// Orders the children of a node by increasing cost of the edit leading to them.
+    /// This only orders siblings, not the whole exploration by cumulative cost.
+    /// Costs which do not fit in an i32 get the lowest priority.
+    pub fn from_costs(costs : &NfaWordRepairCosts) -> Self {
+        let priority = |cost : u32| i32::try_from(cost).map_or(i32::MIN, |cost| -cost);
+        Self::new(0,
+                  priority(costs.insertion),
+                  priority(costs.deletion),
+                  priority(costs.substitution))
+    }
+}
+
+impl Default for NfaWordRepairPriorities {
+    /// Synchronous reads are explored first.
+    fn default() -> Self {
+        NfaWordRepairPriorities::new(1,0,0,0)
+    }
+}
+
+
+impl fmt::Display for NfaWordRepairPriorities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"[read={:},insertion={:},deletion={:},substitution={:}]",
+               self.read,
+               self.insertion,
+               self.deletion,
+               self.substitution)
+    }
+}
+
+impl AbstractPriorities<NfaWordRepairStepKind> for NfaWordRepairPriorities {
+    fn get_priority_of_step(&self, step: &NfaWordRepairStepKind) -> i32 {
+        match step {
+            NfaWordRepairStepKind::Read(_) => {
+                self.read
+            },
+            NfaWordRepairStepKind::Insert(_,_) => {
+                self.insertion
+            },
+            NfaWordRepairStepKind::Delete => {
+                self.deletion
+            },
+            NfaWordRepairStepKind::Substitute(_,_) => {
+                self.substitution
+            }
+        }
+    }
+}
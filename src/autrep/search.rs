@@ -0,0 +1,100 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autrep::handling::handler::get_repair_steps;
+use crate::autrep::loggers::rlog::repair::NfaWordRepair;
+use crate::autrep::node::NfaWordRepairNodeKind;
+use crate::autrep::param::{NfaWordRepairCosts, NfaWordRepairParameterization};
+use crate::autrep::step::NfaWordRepairStepKind;
+
+
+type NfaWordRepairConfiguration = (BTreeSet<usize>,usize);
+
+/// Computes a repair of minimal cost of the word using Dijkstra's algorithm
+/// over the configurations (set of active states, position in the word).
+/// Returns None if the NFA accepts no word.
+pub fn get_minimal_repair(nfa : &AutNFAIT<usize>,
+                          word : &[usize],
+                          costs : &NfaWordRepairCosts) -> Option<NfaWordRepair> {
+    let init = NfaWordRepairParameterization::new(costs.clone()).make_init_node(nfa);
+    let init : NfaWordRepairConfiguration = (init.active_states,init.pos_in_trace);
+    // configurations are numbered so that the heap only contains integers
+    let mut configurations : Vec<NfaWordRepairConfiguration> = vec![init.clone()];
+    let mut ids : HashMap<NfaWordRepairConfiguration,usize> = HashMap::new();
+    ids.insert(init,0);
+    let mut best_costs : Vec<u32> = vec![0];
+    let mut parents : Vec<Option<(usize,NfaWordRepairStepKind)>> = vec![None];
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0,0)));
+    while let Some(Reverse((cost,id))) = heap.pop() {
+        if cost > best_costs[id] {
+            // outdated entry
+            continue;
+        }
+        let (states,pos) = configurations[id].clone();
+        if pos == word.len() && states.iter().any(|st| nfa.finals.contains(st)) {
+            let mut steps = vec![];
+            let mut current = id;
+            while let Some((parent,step)) = &parents[current] {
+                steps.push(step.clone());
+                current = *parent;
+            }
+            steps.reverse();
+            return Some(NfaWordRepair::new(cost,steps));
+        }
+        let node_kind = NfaWordRepairNodeKind::new(states,pos,cost);
+        for step in get_repair_steps(nfa,word,&node_kind) {
+            let new_cost = cost.saturating_add(costs.get_step_cost(&step));
+            let configuration : NfaWordRepairConfiguration = match &step {
+                NfaWordRepairStepKind::Read(new_active) |
+                NfaWordRepairStepKind::Substitute(_,new_active) => {
+                    (new_active.clone(),pos + 1)
+                },
+                NfaWordRepairStepKind::Insert(_,new_active) => {
+                    (new_active.clone(),pos)
+                },
+                NfaWordRepairStepKind::Delete => {
+                    (node_kind.active_states.clone(),pos + 1)
+                }
+            };
+            let new_id = match ids.get(&configuration) {
+                Some(known_id) => {
+                    if new_cost >= best_costs[*known_id] {
+                        continue;
+                    }
+                    best_costs[*known_id] = new_cost;
+                    parents[*known_id] = Some((id,step));
+                    *known_id
+                },
+                None => {
+                    let new_id = configurations.len();
+                    ids.insert(configuration.clone(),new_id);
+                    configurations.push(configuration);
+                    best_costs.push(new_cost);
+                    parents.push(Some((id,step)));
+                    new_id
+                }
+            };
+            heap.push(Reverse((new_cost,new_id)));
+        }
+    }
+    None
+}
@@ -0,0 +1,61 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fmt::Formatter;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaWordRepairStepKind {
+    // ***
+    // read the next letter in the word and go to next set of active states
+    Read(BTreeSet<usize>),
+    // ***
+    // read a letter which does not occur in the word
+    // contains the inserted letter and the new set of active states
+    Insert(usize,BTreeSet<usize>),
+    // ***
+    // ignore the next letter in the word
+    Delete,
+    // ***
+    // read a letter in place of the next letter in the word
+    // contains the substituted letter and the new set of active states
+    Substitute(usize,BTreeSet<usize>)
+}
+
+impl fmt::Display for NfaWordRepairStepKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaWordRepairStepKind::Read(_) => {
+                write!(f,"read")
+            },
+            NfaWordRepairStepKind::Insert(_,_) => {
+                write!(f,"insert")
+            },
+            NfaWordRepairStepKind::Delete => {
+                write!(f,"delete")
+            },
+            NfaWordRepairStepKind::Substitute(_,_) => {
+                write!(f,"substitute")
+            }
+        }
+    }
+}
+
+
+
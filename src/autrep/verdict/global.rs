@@ -0,0 +1,98 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
+
+use crate::autrep::verdict::local::NfaWordRepairLocalVerdict;
+
+
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct NfaWordRepairGlobalVerdict{
+    // minimal cost of the repairs found so far, if any
+    pub min_cost : Option<u32>
+}
+
+impl NfaWordRepairGlobalVerdict {
+    pub fn new(min_cost: Option<u32>) -> Self {
+        Self { min_cost }
+    }
+}
+
+impl fmt::Display for NfaWordRepairGlobalVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.min_cost {
+            None => {
+                write!(f,"no repair found")
+            },
+            Some(0) => {
+                write!(f,"word accepted without edits")
+            },
+            Some(cost) => {
+                write!(f,"word repaired with minimal cost {:}", cost)
+            }
+        }
+    }
+}
+
+impl AbstractGlobalVerdict<NfaWordRepairLocalVerdict> for NfaWordRepairGlobalVerdict {
+
+    fn is_verdict_pertinent_for_process() -> bool {
+        true
+    }
+
+    fn get_baseline_verdict() -> Self {
+        NfaWordRepairGlobalVerdict::new(None)
+    }
+
+    fn update_with_local_verdict(self,
+                                 local_verdict: &NfaWordRepairLocalVerdict) -> Self {
+        match (local_verdict,self.min_cost) {
+            (NfaWordRepairLocalVerdict::Repaired(cost),Some(min_cost)) => {
+                NfaWordRepairGlobalVerdict::new(Some(min_cost.min(*cost)))
+            },
+            (NfaWordRepairLocalVerdict::Repaired(cost),None) => {
+                NfaWordRepairGlobalVerdict::new(Some(*cost))
+            },
+            (NfaWordRepairLocalVerdict::DeadEnd,_) => {
+                self
+            }
+        }
+    }
+
+    fn is_goal_reached(&self,
+                       goal: &Option<Self>) -> bool {
+        // the goal is to find a repair costing at most a given cost
+        match (goal,self.min_cost) {
+            (Some(NfaWordRepairGlobalVerdict{min_cost:Some(max_cost)}),Some(min_cost)) => {
+                min_cost <= *max_cost
+            },
+            _ => {
+                false
+            }
+        }
+    }
+
+    fn update_knowing_nodes_were_filtered_out(self,
+                                              _has_filtered_nodes: bool) -> Self {
+        self
+    }
+
+}
+
+
@@ -0,0 +1,60 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use autour_core::traits::repr::AbstractLanguagePrinter;
+
+
+/// An interval [start,end[ of the word whose factor is accepted by the NFA up to a number of edits.
+#[derive(Clone, PartialEq, Debug, Eq, Hash, PartialOrd, Ord)]
+pub struct NfaWordMatch {
+    pub start : usize,
+    pub end : usize,
+    pub edits : u32
+}
+
+impl NfaWordMatch {
+    pub fn new(start: usize, end: usize, edits: u32) -> Self {
+        Self { start, end, edits }
+    }
+
+    pub fn factor_to_string<Printer : AbstractLanguagePrinter<usize>>(&self,
+                                                                      word : &[usize],
+                                                                      printer : &Printer) -> String {
+        let as_letters : Vec<String> = word[self.start..self.end].iter()
+            .map(|l| printer.get_letter_string_repr(l)).collect();
+        as_letters.join(printer.get_concatenation_separator(false))
+    }
+}
+
+/// Prints the word with the matched factors between brackets.
+/// Matches are expected to be ordered and not to overlap, as with the leftmost-longest mode.
+pub fn highlight_matches<Printer : AbstractLanguagePrinter<usize>>(word : &[usize],
+                                                                   matches : &[NfaWordMatch],
+                                                                   printer : &Printer) -> String {
+    let mut items = vec![];
+    let mut pos = 0;
+    for matched in matches {
+        if matched.start < pos {
+            continue;
+        }
+        items.extend(word[pos..matched.start].iter().map(|l| printer.get_letter_string_repr(l)));
+        items.push(format!("[{}]",matched.factor_to_string(word,printer)));
+        pos = matched.end;
+    }
+    items.extend(word[pos..].iter().map(|l| printer.get_letter_string_repr(l)));
+    items.join(printer.get_concatenation_separator(false))
+}
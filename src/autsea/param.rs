@@ -0,0 +1,60 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+use std::fmt::Formatter;
+
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum NfaWordSearchMode {
+    // the longest match starting at the leftmost position, then the search resumes after it, as with grep
+    LeftmostLongest,
+    // every interval whose factor is matched
+    AllMatches
+}
+
+impl fmt::Display for NfaWordSearchMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NfaWordSearchMode::LeftmostLongest => {
+                write!(f,"leftmost-longest")
+            },
+            NfaWordSearchMode::AllMatches => {
+                write!(f,"all matches")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub struct NfaWordSearchParameterization {
+    pub mode : NfaWordSearchMode,
+    // maximal number of edits (insertions, deletions or substitutions of letters) allowed in a matched factor
+    pub max_edits : u32
+}
+
+impl NfaWordSearchParameterization {
+    pub fn new(mode: NfaWordSearchMode, max_edits: u32) -> Self {
+        Self { mode, max_edits }
+    }
+}
+
+impl fmt::Display for NfaWordSearchParameterization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f,"{:} with at most {:} edits",self.mode,self.max_edits)
+    }
+}
@@ -0,0 +1,139 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use maplit::btreeset;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::node::{get_epsilon_closure, get_successors};
+use crate::autsea::matching::NfaWordMatch;
+use crate::autsea::param::{NfaWordSearchMode, NfaWordSearchParameterization};
+
+
+/// Maps active states to the minimal number of edits with which they are reached.
+type NfaWordSearchConfiguration = BTreeMap<usize,u32>;
+
+/// Returns the intervals of the word whose (non-empty) factors are accepted by the NFA.
+pub fn search_matches(nfa : &AutNFAIT<usize>,
+                      word : &[usize],
+                      param : &NfaWordSearchParameterization) -> Vec<NfaWordMatch> {
+    let mut matches = vec![];
+    let mut start = 0;
+    while start < word.len() {
+        let from_start = search_matches_from(nfa,word,start,param.max_edits);
+        match param.mode {
+            NfaWordSearchMode::AllMatches => {
+                matches.extend(from_start);
+                start += 1;
+            },
+            NfaWordSearchMode::LeftmostLongest => {
+                match from_start.into_iter().last() {
+                    None => {
+                        start += 1;
+                    },
+                    Some(longest) => {
+                        start = longest.end;
+                        matches.push(longest);
+                    }
+                }
+            }
+        }
+    }
+    matches
+}
+
+/// Returns the matches starting at the given position, ordered by increasing end.
+fn search_matches_from(nfa : &AutNFAIT<usize>,
+                       word : &[usize],
+                       start : usize,
+                       max_edits : u32) -> Vec<NfaWordMatch> {
+    let initials : BTreeSet<usize> = nfa.initials.iter().cloned().collect();
+    let mut configuration : NfaWordSearchConfiguration = get_epsilon_closure(nfa,&initials)
+        .into_iter().map(|st| (st,0)).collect();
+    configuration = close_under_insertions(nfa,configuration,max_edits);
+    let mut matches = vec![];
+    for (end,letter) in word.iter().enumerate().skip(start) {
+        let mut next : NfaWordSearchConfiguration = BTreeMap::new();
+        for (state,edits) in &configuration {
+            let origin = btreeset!{*state};
+            // the letter is read
+            for target in get_successors(nfa,&origin,letter) {
+                add_with_edits(&mut next,target,*edits);
+            }
+            if *edits < max_edits {
+                // the letter is deleted
+                add_with_edits(&mut next,*state,edits + 1);
+                // the letter is substituted
+                for other in nfa.transitions[*state].keys().filter(|other| *other != letter) {
+                    for target in get_successors(nfa,&origin,other) {
+                        add_with_edits(&mut next,target,edits + 1);
+                    }
+                }
+            }
+        }
+        configuration = close_under_insertions(nfa,next,max_edits);
+        if configuration.is_empty() {
+            break;
+        }
+        let accepting_edits = configuration.iter()
+            .filter(|(state,_)| nfa.finals.contains(state))
+            .map(|(_,edits)| *edits)
+            .min();
+        if let Some(edits) = accepting_edits {
+            matches.push(NfaWordMatch::new(start,end + 1,edits));
+        }
+    }
+    matches
+}
+
+/// Adds the states reachable by inserting letters, as long as the number of edits remains bounded.
+fn close_under_insertions(nfa : &AutNFAIT<usize>,
+                          mut configuration : NfaWordSearchConfiguration,
+                          max_edits : u32) -> NfaWordSearchConfiguration {
+    let mut to_expand : Vec<(usize,u32)> = configuration.iter().map(|(st,e)| (*st,*e)).collect();
+    while let Some((state,edits)) = to_expand.pop() {
+        if edits >= max_edits || configuration.get(&state) != Some(&edits) {
+            continue;
+        }
+        let origin = btreeset!{state};
+        for letter in nfa.transitions[state].keys() {
+            for target in get_successors(nfa,&origin,letter) {
+                if add_with_edits(&mut configuration,target,edits + 1) {
+                    to_expand.push((target,edits + 1));
+                }
+            }
+        }
+    }
+    configuration
+}
+
+/// Returns true if the state was not reached before with as few edits.
+fn add_with_edits(configuration : &mut NfaWordSearchConfiguration,
+                  state : usize,
+                  edits : u32) -> bool {
+    match configuration.get(&state) {
+        Some(known) if *known <= edits => {
+            false
+        },
+        _ => {
+            configuration.insert(state,edits);
+            true
+        }
+    }
+}
@@ -16,6 +16,16 @@ limitations under the License.
 
 
 pub mod autana;
+pub mod autrep;
+pub mod autali;
+pub mod autsea;
+pub mod autconf;
+pub mod autprod;
+pub mod autime;
+pub mod autdata;
+pub mod autlang;
+pub mod autprob;
+pub mod autpor;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,84 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::autali::costs::NfaWordAlignmentCosts;
+use crate::autali::moves::NfaWordAlignmentMove;
+use crate::autali::search::get_optimal_alignment;
+
+use crate::tests::nfa::{get_rv_nfa, get_eps_nfa};
+
+
+#[test]
+fn tests_alignment() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // with costly log moves, the missing letter is a model move
+    let word = vec![letter("a"),letter("b"),letter("a")];
+    let alignment = get_optimal_alignment(&nfa,&word,&NfaWordAlignmentCosts::new(2,1)).unwrap();
+    assert_eq!(alignment.moves,
+               vec![NfaWordAlignmentMove::Synchronous(letter("a")),
+                    NfaWordAlignmentMove::Synchronous(letter("b")),
+                    NfaWordAlignmentMove::ModelOnly(letter("c")),
+                    NfaWordAlignmentMove::Synchronous(letter("a"))]);
+    assert_eq!(alignment.cost, 1);
+    // the worst alignment only has log moves as the empty word is accepted
+    assert_eq!(alignment.worst_cost, 6);
+    assert_eq!(alignment.to_table_string(&printer),
+               "log   | a | b | >> | a\nmodel | a | b | c  | a".to_string());
+    // a letter unknown to the NFA is a log move
+    let word = vec![letter("a"),letter("d"),letter("b"),letter("c")];
+    let alignment = get_optimal_alignment(&nfa,&word,&NfaWordAlignmentCosts::default()).unwrap();
+    assert_eq!(alignment.cost, 1);
+    assert_eq!(alignment.moves[1], NfaWordAlignmentMove::LogOnly(letter("d")));
+    assert_eq!(alignment.get_fitness(), 0.75);
+    // per-letter costs override the default ones
+    // a costly model move on "c" makes skipping "b" cheaper than completing the cycle
+    let word = vec![letter("a"),letter("b"),letter("a")];
+    let costs = NfaWordAlignmentCosts::new(2,1).with_model_move_cost(letter("c"),5);
+    let alignment = get_optimal_alignment(&nfa,&word,&costs).unwrap();
+    assert_eq!(alignment.moves,
+               vec![NfaWordAlignmentMove::Synchronous(letter("a")),
+                    NfaWordAlignmentMove::LogOnly(letter("b")),
+                    NfaWordAlignmentMove::Synchronous(letter("a"))]);
+    assert_eq!(alignment.cost, 2);
+    let word = vec![letter("a"),letter("d"),letter("b"),letter("c")];
+    let costs = NfaWordAlignmentCosts::default().with_log_move_cost(letter("d"),3);
+    let alignment = get_optimal_alignment(&nfa,&word,&costs).unwrap();
+    assert_eq!((alignment.cost,alignment.moves[1].clone()), (3,NfaWordAlignmentMove::LogOnly(letter("d"))));
+}
+
+
+#[test]
+fn tests_alignment_epsilon() {
+    let (printer,nfa) = get_eps_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // "c" can be read after "a" through the epsilon transition
+    let word = vec![letter("a"),letter("c")];
+    let alignment = get_optimal_alignment(&nfa,&word,&NfaWordAlignmentCosts::default()).unwrap();
+    assert_eq!(alignment.moves,
+               vec![NfaWordAlignmentMove::Synchronous(letter("a")),
+                    NfaWordAlignmentMove::Synchronous(letter("c"))]);
+    assert_eq!(alignment.cost, 0);
+    // the word ends after the second "a" hence the model completes the cycle with "c" through the epsilon transition
+    let word = vec![letter("a"),letter("c"),letter("a")];
+    let alignment = get_optimal_alignment(&nfa,&word,&NfaWordAlignmentCosts::new(2,1)).unwrap();
+    assert_eq!(alignment.moves,
+               vec![NfaWordAlignmentMove::Synchronous(letter("a")),
+                    NfaWordAlignmentMove::Synchronous(letter("c")),
+                    NfaWordAlignmentMove::Synchronous(letter("a")),
+                    NfaWordAlignmentMove::ModelOnly(letter("c"))]);
+    assert_eq!(alignment.cost, 1);
+}
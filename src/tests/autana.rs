@@ -0,0 +1,709 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use maplit::{btreeset,hashset};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisPolicy, NfaWordAnalysisResetOn};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autana::verdict::local::NfaWordAnalysisLocalVerdict;
+use crate::autana::explanation::{NfaWordAnalysisDeviationExplanation, NfaWordAnalysisExpectedLetter};
+use crate::autana::observation::NfaWordAnalysisObservation;
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::loggers::plog::segment::NfaWordAnalysisSegment;
+use crate::autana::step::NfaWordAnalysisStepKind;
+use crate::autana::demux::analyze_sessions;
+use crate::autana::rank::{rank_specifications, NfaWordAnalysisRanking};
+use crate::autana::diff::compare_specifications;
+use crate::autana::backward::localize_fault;
+use crate::autana::window::{analyze_windows, get_deviations_timeline, NfaWordAnalysisWindowing};
+
+use crate::tests::harness::{ana_test, ana_test_paths, ana_test_observations};
+use crate::tests::nfa::{get_rv_nfa, get_eps_nfa, get_two_cycles_nfa};
+
+
+#[test]
+fn tests() {
+
+    let param_accept = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::StopAtDeviation);
+    let param_accept_start_spec = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Specific(hashset!{1}),
+        NfaWordAnalysisPolicy::StopAtDeviation);
+    let param_reset_no_skip = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::AllStates,false));
+    let param_reset_then_skip = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::AllStates,true));
+
+    let (printer,nfa) = get_rv_nfa();
+    ana_test("accept".to_string(),
+             printer.clone(),
+             param_accept.clone(),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "b".to_string(),
+                 "c".to_string()
+             ]);
+    ana_test("fail".to_string(),
+             printer.clone(),
+             param_accept.clone(),
+             nfa.clone(),
+             vec![
+                 "b".to_string()
+             ]);
+    ana_test("start_away".to_string(),
+             printer.clone(),
+             param_accept_start_spec.clone(),
+             nfa.clone(),
+             vec![
+                 "b".to_string()
+             ]);
+    ana_test("reset".to_string(),
+             printer.clone(),
+             param_reset_no_skip.clone(),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    ana_test("reset_fail".to_string(),
+             printer.clone(),
+             param_reset_no_skip.clone(),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "d".to_string()
+             ]);
+    ana_test("skip".to_string(),
+             printer,
+             param_reset_then_skip.clone(),
+             nfa,
+             vec![
+                 "a".to_string(),
+                 "d".to_string(),
+                 "b".to_string()
+             ]);
+}
+
+
+#[test]
+fn tests_epsilon() {
+
+    let param_accept = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::StopAtDeviation);
+    let param_reset_no_skip = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::Specific(hashset!{1}),false));
+
+    let (printer,nfa) = get_eps_nfa();
+    let verdict = ana_test("eps_accept".to_string(),
+             printer.clone(),
+             param_accept.clone(),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "c".to_string(),
+                 "a".to_string(),
+                 "b".to_string(),
+                 "c".to_string()
+             ]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict::new(0,true));
+    let verdict = ana_test("eps_reset".to_string(),
+             printer,
+             param_reset_no_skip.clone(),
+             nfa,
+             vec![
+                 "a".to_string(),
+                 "c".to_string(),
+                 "c".to_string()
+             ]);
+    // the reset on state 1 also activates its closure and hence allows reading "c"
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{unexpected_letters:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+}
+
+
+#[test]
+fn tests_letter_enabled_reset() {
+
+    let param_reset_on_letter = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::NextLettersEnabled(1),false));
+    let param_reset_on_two_letters = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::NextLettersEnabled(2),true));
+
+    let (printer,nfa) = get_rv_nfa();
+    let verdict = ana_test("reset_letter".to_string(),
+             printer.clone(),
+             param_reset_on_letter,
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{unexpected_letters:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+    // no state enables "c.b" hence "c" is skipped and we reset on the states enabling "b"
+    let verdict = ana_test("reset_two_letters".to_string(),
+             printer,
+             param_reset_on_two_letters,
+             nfa,
+             vec![
+                 "a".to_string(),
+                 "c".to_string(),
+                 "b".to_string()
+             ]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{ineffective_resets:1,
+                                                     premature_ends:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+}
+
+
+#[test]
+fn tests_history_consistent_reset() {
+
+    let param_reset_all = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::AllStates,true));
+    let param_reset_history = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::LastLettersConsistent(1),true));
+
+    let (printer,nfa) = get_rv_nfa();
+    let trace = vec![
+        "a".to_string(),
+        "b".to_string(),
+        "a".to_string(),
+        "c".to_string()
+    ];
+    // resetting on all states allows reading the unexpected "a" and then "c" is a second deviation
+    let verdict = ana_test("reset_all_history".to_string(),
+             printer.clone(),
+             param_reset_all,
+             nfa.clone(),
+             trace.clone());
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{unexpected_letters:2,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(2,true)});
+    // the states consistent with "b" cannot read "a" hence it is skipped and "c" is read
+    let verdict = ana_test("reset_history".to_string(),
+             printer,
+             param_reset_history,
+             nfa,
+             trace);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{ineffective_resets:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+}
+
+
+#[test]
+fn tests_reset_lookaround() {
+    let history = [0,1,2,3];
+    let upcoming = [4,5,6];
+    // resets which do not depend on the word look at none of it
+    for reset in [NfaWordAnalysisResetOn::Initials,
+                  NfaWordAnalysisResetOn::AllStates,
+                  NfaWordAnalysisResetOn::Specific(hashset!{0})] {
+        assert_eq!(reset.get_lookaround(&history,&upcoming), (&[][..],&[][..]));
+    }
+    // the other resets only look at the k letters around the deviation
+    assert_eq!(NfaWordAnalysisResetOn::LastLettersConsistent(2).get_lookaround(&history,&upcoming),
+               (&[2,3][..],&[][..]));
+    assert_eq!(NfaWordAnalysisResetOn::NextLettersEnabled(5).get_lookaround(&history,&upcoming),
+               (&[][..],&[4,5,6][..]));
+}
+
+
+#[test]
+fn tests_branching_resets() {
+    let (printer,nfa) = get_two_cycles_nfa();
+    let param_branch = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::BranchOnResets(NfaWordAnalysisResetOn::each_initial(&nfa),true));
+
+    let (verdict,paths) = ana_test_paths("branch".to_string(),
+             printer.clone(),
+             param_branch.clone(),
+             nfa.clone(),
+             vec![
+                 "b".to_string(),
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    // "b" is skipped and both initial states are tried as separate branches
+    // only resetting on state 2 explains the rest of the trace
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert!(best.has_emptied_trace());
+    assert_eq!(best.get_deviations(), 1);
+    assert_eq!(best.get_resynchronizations(),
+               vec![(0,&NfaWordAnalysisStepKind::ResetOnAlternativeAndOrSkip(1,true))]);
+    // the verdict is that of the best path and not the sum over both branches
+    assert_eq!(verdict, best.get_verdict());
+    assert_eq!((verdict.deviations,verdict.ineffective_resets,verdict.emptied_trace), (1,1,true));
+
+    let (verdict,paths) = ana_test_paths("branch_longer".to_string(),
+             printer,
+             param_branch,
+             nfa,
+             vec![
+                 "b".to_string(),
+                 "a".to_string(),
+                 "c".to_string(),
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    // the branch which resets on state 1 deviates at each "c"
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!(best.get_deviations(), 1);
+    assert_eq!((verdict.deviations,verdict.ineffective_resets,verdict.emptied_trace), (1,1,true));
+}
+
+
+#[test]
+fn tests_deviation_kinds() {
+    let (printer,nfa) = get_rv_nfa();
+
+    let param_reset_then_skip = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::AllStates,true));
+    let verdict = ana_test("unknown_letter".to_string(),
+             printer.clone(),
+             param_reset_then_skip,
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "d".to_string(),
+                 "b".to_string()
+             ]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{unknown_letters:1,
+                                                     premature_ends:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+
+    let param_skip = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let verdict = ana_test("unexpected_letter".to_string(),
+             printer.clone(),
+             param_skip,
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{unexpected_letters:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+
+    let param_reset_history = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::LastLettersConsistent(1),true));
+    let verdict = ana_test("reset_ineffective".to_string(),
+             printer,
+             param_reset_history,
+             nfa,
+             vec![
+                 "a".to_string(),
+                 "b".to_string(),
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict{ineffective_resets:1,
+                                                     ..NfaWordAnalysisGlobalVerdict::new(1,true)});
+}
+
+
+#[test]
+fn tests_expected_letters() {
+    let (printer,nfa) = get_rv_nfa();
+    let param_accept = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::StopAtDeviation);
+    let (_,paths) = ana_test_paths("expected".to_string(),
+             printer.clone(),
+             param_accept,
+             nfa,
+             vec![
+                 "a".to_string(),
+                 "c".to_string()
+             ]);
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!(best.explanations,
+               vec![NfaWordAnalysisDeviationExplanation::new(
+                   1,
+                   NfaWordAnalysisObservation::Letter(letter("c")),
+                   vec![
+                       NfaWordAnalysisExpectedLetter::new(letter("a"),Some(vec![letter("a")])),
+                       NfaWordAnalysisExpectedLetter::new(letter("b"),Some(vec![letter("b"),letter("c")]))
+                   ])]);
+}
+
+
+#[test]
+fn tests_completions() {
+    let (printer,nfa) = get_rv_nfa();
+    let param_accept = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::StopAtDeviation);
+    let (verdict,paths) = ana_test_paths("completions".to_string(),
+             printer.clone(),
+             param_accept,
+             nfa,
+             vec![
+                 "a".to_string(),
+                 "b".to_string()
+             ]);
+    assert_eq!(verdict.premature_ends, 1);
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!(best.verdict, NfaWordAnalysisLocalVerdict::PrematureEnd);
+    assert_eq!(best.completions, vec![vec![letter("c")],vec![letter("c"),letter("a")]]);
+}
+
+
+#[test]
+fn tests_windows() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let word : Vec<usize> = ["a","b","c","a","b","c","d","a","b","c"].iter().map(|x| letter(x)).collect();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::AllStates,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let windows = analyze_windows(&nfa,&printer,&word,&param,&NfaWordAnalysisWindowing::Tumbling(3));
+    assert_eq!(windows.iter().map(|w| (w.start,w.end)).collect::<Vec<(usize,usize)>>(),
+               vec![(0,3),(3,6),(6,9),(9,10)]);
+    assert_eq!(get_deviations_timeline(&windows), vec![0,0,1,0]);
+    let windows = analyze_windows(&nfa,&printer,&word,&param,&NfaWordAnalysisWindowing::Sliding(6));
+    assert_eq!(get_deviations_timeline(&windows), vec![0,1,1,1,1]);
+    // an empty word has no windows, whatever the windowing
+    assert!(NfaWordAnalysisWindowing::Tumbling(3).get_windows(0).is_empty());
+    assert!(NfaWordAnalysisWindowing::Sliding(3).get_windows(0).is_empty());
+    assert!(analyze_windows(&nfa,&printer,&[],&param,&NfaWordAnalysisWindowing::Sliding(6)).is_empty());
+    // a word shorter than the windows is a single window
+    assert_eq!(NfaWordAnalysisWindowing::Sliding(3).get_windows(2), vec![(0,2)]);
+    // when the policy branches, the deviations of a window are those of its best path
+    let (_,two_cycles_nfa) = get_two_cycles_nfa();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::BranchOnResets(NfaWordAnalysisResetOn::each_initial(&two_cycles_nfa),true));
+    let word : Vec<usize> = ["b","a","c","a","c","b","a","c","a","c"].iter().map(|x| letter(x)).collect();
+    let windows = analyze_windows(&two_cycles_nfa,&printer,&word,&param,&NfaWordAnalysisWindowing::Tumbling(5));
+    assert_eq!(get_deviations_timeline(&windows), vec![1,1]);
+}
+
+
+#[test]
+#[should_panic(expected = "windows must contain at least one letter")]
+fn tests_windows_tumbling_empty_size() {
+    NfaWordAnalysisWindowing::Tumbling(0).get_windows(4);
+}
+
+
+#[test]
+#[should_panic(expected = "windows must contain at least one letter")]
+fn tests_windows_sliding_empty_size() {
+    NfaWordAnalysisWindowing::Sliding(0).get_windows(4);
+}
+
+
+#[test]
+fn tests_segments() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(Some(NfaWordAnalysisResetOn::Initials)));
+    // the event after the second "a" is not known
+    let mut word : Vec<NfaWordAnalysisObservation> = ["a","b","c","d","a","b","c","a","b"].iter()
+        .map(|x| NfaWordAnalysisObservation::Letter(letter(x))).collect();
+    word[5] = NfaWordAnalysisObservation::Unknown;
+    let (_,paths) = ana_test_observations("segments".to_string(),
+             printer.clone(),
+             param,
+             nfa.clone(),
+             word.clone());
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    // the skipped letter "d" belongs to no session
+    assert_eq!(best.get_segments(&nfa,&word),
+               vec![NfaWordAnalysisSegment::new(0,3,word[0..3].to_vec(),true,btreeset!{0}),
+                    NfaWordAnalysisSegment::new(4,9,word[4..9].to_vec(),false,btreeset!{2})]);
+}
+
+
+#[test]
+fn tests_sessions() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    // session 1 reads "a.b.c" and session 2 reads "a.c.b.c"
+    let events : Vec<(u32,usize)> = vec![(1,"a"),(2,"a"),(2,"c"),(1,"b"),(2,"b"),(1,"c"),(2,"c")]
+        .into_iter().map(|(key,x)| (key,letter(x))).collect();
+    let reports = analyze_sessions(&nfa,&printer,&events,&param);
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].positions, vec![0,3,5]);
+    assert_eq!(reports[0].verdict.deviations, 0);
+    assert_eq!(reports[1].positions, vec![1,2,4,6]);
+    assert_eq!(reports[1].verdict.deviations, 1);
+    assert_eq!(reports[1].get_deviation_positions(), vec![2]);
+    // when the policy branches, the counts reported for a session are those of its best path
+    let (_,two_cycles_nfa) = get_two_cycles_nfa();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::BranchOnResets(NfaWordAnalysisResetOn::each_initial(&two_cycles_nfa),true));
+    let events : Vec<(u32,usize)> = vec![(1,"b"),(2,"a"),(1,"a"),(2,"b"),(1,"c"),(1,"a"),(1,"c")]
+        .into_iter().map(|(key,x)| (key,letter(x))).collect();
+    let reports = analyze_sessions(&two_cycles_nfa,&printer,&events,&param);
+    assert_eq!((reports[0].verdict.deviations,reports[0].get_deviation_positions()), (1,vec![0]));
+    assert_eq!((reports[1].verdict.deviations,reports[1].get_deviation_positions()), (0,vec![]));
+}
+
+
+#[test]
+fn tests_ranking() {
+    let (printer,rv_nfa) = get_rv_nfa();
+    let (_,eps_nfa) = get_eps_nfa();
+    let (_,two_cycles_nfa) = get_two_cycles_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let word : Vec<usize> = ["a","b","a","c"].iter().map(|x| letter(x)).collect();
+    let rankings = rank_specifications(&[rv_nfa,eps_nfa,two_cycles_nfa],&printer,&word,&param);
+    // all specifications deviate once and the NFA with two cycles only deviates on the last letter
+    // but the word then ends prematurely in it, hence it explains the word the least
+    // the two others deviate on the third letter and keep their relative order
+    assert_eq!(rankings.iter().map(|r| r.spec_index).collect::<Vec<usize>>(), vec![0,1,2]);
+    assert_eq!(rankings.iter().map(|r| r.verdict.deviations).collect::<Vec<u32>>(), vec![1,1,1]);
+    assert_eq!(rankings.iter().map(|r| r.reenacted_prefix).collect::<Vec<usize>>(), vec![2,2,3]);
+    // a specification which rejects the word without deviating explains it worse than one which accepts it
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::StopAtDeviation);
+    let word : Vec<usize> = ["a","b","a","b"].iter().map(|x| letter(x)).collect();
+    let (_,rv_nfa) = get_rv_nfa();
+    let (_,two_cycles_nfa) = get_two_cycles_nfa();
+    let rankings = rank_specifications(&[rv_nfa,two_cycles_nfa],&printer,&word,&param);
+    assert_eq!(rankings.iter().map(|r| r.spec_index).collect::<Vec<usize>>(), vec![1,0]);
+    assert_eq!(rankings[1].verdict, NfaWordAnalysisGlobalVerdict::new(0,false));
+    let rejecting = NfaWordAnalysisRanking::new(0,NfaWordAnalysisGlobalVerdict::new(0,false),3);
+    let accepting = NfaWordAnalysisRanking::new(1,NfaWordAnalysisGlobalVerdict::new(1,true),2);
+    assert!(accepting.explains_better_than(&rejecting));
+    assert!(!rejecting.explains_better_than(&accepting));
+    // as well as a specification in which the word ends prematurely
+    let premature = NfaWordAnalysisRanking::new(2,NfaWordAnalysisGlobalVerdict{premature_ends:1,
+                                                                               ..NfaWordAnalysisGlobalVerdict::new(1,true)},4);
+    assert!(accepting.explains_better_than(&premature));
+    assert!(!premature.explains_better_than(&accepting));
+    // when the policy branches, specifications are ranked from their best path
+    let (_,rv_nfa) = get_rv_nfa();
+    let (_,two_cycles_nfa) = get_two_cycles_nfa();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::BranchOnResets(NfaWordAnalysisResetOn::each_initial(&two_cycles_nfa),true));
+    let word : Vec<usize> = ["b","a","c","a","c"].iter().map(|x| letter(x)).collect();
+    let rankings = rank_specifications(&[rv_nfa,two_cycles_nfa],&printer,&word,&param);
+    // resetting on state 2 when skipping "b" explains the rest of the word with two cycles
+    // whereas the branch resetting on state 0 deviates on each "c"
+    assert_eq!(rankings.iter().map(|r| (r.spec_index,r.verdict.deviations)).collect::<Vec<(usize,u32)>>(),
+               vec![(1,1),(0,3)]);
+}
+
+
+#[test]
+fn tests_differences() {
+    let (printer,rv_nfa) = get_rv_nfa();
+    let (_,eps_nfa) = get_eps_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let corpus : Vec<Vec<usize>> = vec![
+        vec!["a","b","c"],
+        vec!["a","a","b","c"],
+        vec!["a","c"]
+    ].into_iter().map(|trace| trace.iter().map(|x| letter(x)).collect()).collect();
+    let differences = compare_specifications(&rv_nfa,&eps_nfa,&printer,&corpus,&param);
+    // "a.b.c" is accepted by both
+    assert_eq!(differences.iter().map(|d| d.trace_index).collect::<Vec<usize>>(), vec![1,2]);
+    // the second "a" is unexpected in the new NFA
+    assert_eq!(differences[0].divergence, Some(1));
+    // "c" is unexpected in the old NFA while the epsilon transition allows it in the new one
+    assert_eq!(differences[1].divergence, Some(1));
+    // removing the transition on "a" from state 0 of the NFA with two cycles
+    // changes the branch which resets on state 0 but not the best path, which resets on state 2
+    let (_,two_cycles_nfa) = get_two_cycles_nfa();
+    let mut transitions = two_cycles_nfa.transitions.clone();
+    transitions[0].clear();
+    let one_cycle_nfa = AutNFAIT::<usize>::from_raw(two_cycles_nfa.alphabet.clone(),
+                                                    two_cycles_nfa.initials.clone(),
+                                                    two_cycles_nfa.finals.clone(),
+                                                    transitions,
+                                                    two_cycles_nfa.epsilon_trans.clone()).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::BranchOnResets(NfaWordAnalysisResetOn::each_initial(&two_cycles_nfa),true));
+    let corpus : Vec<Vec<usize>> = vec![
+        vec!["b","a","c","a","c"],
+        vec!["a","b"]
+    ].into_iter().map(|trace| trace.iter().map(|x| letter(x)).collect()).collect();
+    let differences = compare_specifications(&two_cycles_nfa,&one_cycle_nfa,&printer,&corpus,&param);
+    assert_eq!(differences.iter().map(|d| d.trace_index).collect::<Vec<usize>>(), vec![1]);
+}
+
+
+#[test]
+fn tests_observations() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| NfaWordAnalysisObservation::Letter(printer.map.iter().position(|y| y == x).unwrap());
+    let one_of = |xs : Vec<&str>| NfaWordAnalysisObservation::OneOf(
+        xs.into_iter().map(|x| printer.map.iter().position(|y| y == x).unwrap()).collect());
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    // the unknown event may be "b"
+    let (verdict,_) = ana_test_observations("obs_unknown".to_string(),
+             printer.clone(),
+             param.clone(),
+             nfa.clone(),
+             vec![letter("a"),NfaWordAnalysisObservation::Unknown,letter("c")]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict::new(0,true));
+    // the event is either "b" or "d"
+    let (verdict,_) = ana_test_observations("obs_one_of".to_string(),
+             printer.clone(),
+             param.clone(),
+             nfa.clone(),
+             vec![letter("a"),one_of(vec!["b","d"]),letter("c")]);
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict::new(0,true));
+    // neither "c" nor "d" can be read after "a"
+    let (verdict,paths) = ana_test_observations("obs_deviation".to_string(),
+             printer.clone(),
+             param,
+             nfa,
+             vec![letter("a"),one_of(vec!["c","d"]),letter("b"),letter("c")]);
+    assert_eq!((verdict.deviations,verdict.emptied_trace), (1,true));
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!(best.explanations.iter().map(|exp| exp.observation.clone()).collect::<Vec<_>>(),
+               vec![one_of(vec!["c","d"])]);
+}
+
+
+#[test]
+fn tests_backward() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    // reading forward blames the second "b" and reading backward the first one
+    let word = vec![letter("a"),letter("b"),letter("b"),letter("c")];
+    let localization = localize_fault(&nfa,printer.clone(),&word,&param);
+    assert_eq!((localization.forward_bound,localization.backward_bound), (Some(2),Some(1)));
+    assert_eq!(localization.get_window(), Some((1,2)));
+    // the missing "b" is blamed on "c" forward and on "a" backward
+    let word = vec![letter("a"),letter("b"),letter("c"),letter("a"),letter("c")];
+    let localization = localize_fault(&nfa,printer.clone(),&word,&param);
+    assert_eq!(localization.get_window(), Some((3,4)));
+    // the word conforms
+    let word = vec![letter("a"),letter("b"),letter("c")];
+    let localization = localize_fault(&nfa,printer.clone(),&word,&param);
+    assert_eq!((localization.forward_verdict.deviations,localization.get_window()), (0,None));
+    // reading forward from state 1, the missing "b" is blamed on the last "c"
+    // whereas reading backward still starts from the final state and blames "a"
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Specific(hashset!{1}),
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let word = vec![letter("b"),letter("c"),letter("a"),letter("c")];
+    let localization = localize_fault(&nfa,printer,&word,&param);
+    assert_eq!((localization.forward_bound,localization.backward_bound), (Some(3),Some(2)));
+    assert_eq!(localization.get_window(), Some((2,3)));
+}
+
+
+#[test]
+fn tests_reordering() {
+    let (printer,nfa) = get_rv_nfa();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(Some(NfaWordAnalysisResetOn::Initials)));
+    let to_trace = |xs : Vec<&str>| -> Vec<String> { xs.into_iter().map(|x| x.to_string()).collect() };
+    // "b" and "c" are swapped
+    // skipping "c" is explored alongside the swap but the best path reads them swapped
+    let (verdict,paths) = ana_test_paths("reorder_adjacent".to_string(),
+             printer.clone(),
+             param.clone().with_max_swap_distance(1),
+             nfa.clone(),
+             to_trace(vec!["a","c","b","a","b","c"]));
+    assert_eq!((verdict.deviations,verdict.reorderings,verdict.emptied_trace), (1,1,true));
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!((best.verdict.clone(),best.get_deviations()), (NfaWordAnalysisLocalVerdict::EmptiedTrace,1));
+    assert!(matches!(best.get_resynchronizations()[0], (1,NfaWordAnalysisStepKind::ReadSwapped(1,_))));
+    // without tolerance the swap costs two deviations
+    let verdict = ana_test("reorder_none".to_string(),
+             printer.clone(),
+             param.clone(),
+             nfa.clone(),
+             to_trace(vec!["a","c","b","a","b","c"]));
+    assert_eq!((verdict.deviations,verdict.reorderings), (2,0));
+    // "a" and "c" are swapped with "b" in between
+    let (verdict,paths) = ana_test_paths("reorder_distant".to_string(),
+             printer.clone(),
+             param.clone().with_max_swap_distance(2),
+             nfa.clone(),
+             to_trace(vec!["c","b","a"]));
+    assert_eq!((verdict.deviations,verdict.reorderings,verdict.emptied_trace), (1,1,true));
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!((best.verdict.clone(),best.get_deviations()), (NfaWordAnalysisLocalVerdict::EmptiedTrace,1));
+    assert!(matches!(best.get_resynchronizations()[0], (0,NfaWordAnalysisStepKind::ReadSwapped(2,_))));
+    // "c" cannot be read swapped with "a" and is skipped
+    // reading "b" and "a" swapped then ends the trace before reaching a final state
+    // so that the best explanation skips "b" as well
+    let verdict = ana_test("reorder_too_distant".to_string(),
+             printer.clone(),
+             param.with_max_swap_distance(1),
+             nfa.clone(),
+             to_trace(vec!["c","b","a"]));
+    assert_eq!((verdict.deviations,verdict.reorderings,verdict.unexpected_letters,verdict.premature_ends), (2,0,2,0));
+    // reading "b" and "c" swapped leads to a dead end on the last "c"
+    // whereas skipping the first "c" as per the policy empties the trace with a single deviation
+    let skip_param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None)).with_max_swap_distance(1);
+    let (_,paths) = ana_test_paths("reorder_dead_end".to_string(),
+             printer.clone(),
+             skip_param,
+             nfa.clone(),
+             to_trace(vec!["a","c","b","c"]));
+    let best = NfaWordAnalysisPath::get_best(&paths).unwrap();
+    assert_eq!((best.verdict.clone(),best.get_deviations()), (NfaWordAnalysisLocalVerdict::EmptiedTrace,1));
+    assert!(matches!(best.get_resynchronizations()[0], (1,NfaWordAnalysisStepKind::ResetAndOrSkip(false,true))));
+    // swaps are not tolerated when stopping at deviations
+    let stop_param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::StopAtDeviation).with_max_swap_distance(1);
+    let verdict = ana_test("reorder_stop".to_string(),
+             printer,
+             stop_param,
+             nfa,
+             to_trace(vec!["a","c","b","a","b","c"]));
+    assert_eq!(verdict, NfaWordAnalysisGlobalVerdict::new(0,false));
+}
@@ -0,0 +1,64 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisPolicy, NfaWordAnalysisResetOn};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autdata::automaton::NfaDataAutomaton;
+use crate::autdata::guard::NfaDataGuard;
+use crate::autdata::semantics::NfaDataViolation;
+
+use crate::tests::harness::conf_test;
+use crate::tests::nfa::get_rv_nfa;
+
+
+#[test]
+fn tests_data() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // "a" opens a resource which "b" uses and "c" closes, and the resource must stay the same
+    // "b" additionally requires a positive amount
+    let get_automaton = || NfaDataAutomaton::new(nfa.clone())
+        .with_binding((0,letter("a"),1),"fd".to_string(),0)
+        .with_guard((1,letter("b"),2),NfaDataGuard::register_equals("fd".to_string(),0))
+        .with_guard((1,letter("b"),2),NfaDataGuard::new("p1 > 0".to_string(),
+                                                        Box::new(|payload,_| payload.get(1).is_some_and(|x| *x > 0))))
+        .with_guard((2,letter("c"),0),NfaDataGuard::register_equals("fd".to_string(),0));
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let data_trace = |events : Vec<(&str,Vec<i64>)>| events.into_iter().map(|(x,p)| (letter(x),p)).collect();
+    let verdict = conf_test("data_ok".to_string(),
+             printer.clone(),
+             param.clone(),
+             get_automaton(),
+             data_trace(vec![("a",vec![3]),("b",vec![3,10]),("c",vec![3]),("a",vec![4]),("b",vec![4,1]),("c",vec![4])]));
+    assert_eq!(verdict.word_verdict, NfaWordAnalysisGlobalVerdict::new(0,true));
+    assert!(verdict.violations.is_empty());
+    // "c" closes another resource than the one which was opened
+    let verdict = conf_test("data_wrong_fd".to_string(),
+             printer.clone(),
+             param.clone(),
+             get_automaton(),
+             data_trace(vec![("a",vec![3]),("b",vec![3,10]),("c",vec![5])]));
+    assert_eq!((verdict.word_verdict.deviations,verdict.get_violations(&NfaDataViolation::GuardViolation)), (1,1));
+    // "b" with a non positive amount is skipped hence "c" becomes unexpected
+    let verdict = conf_test("data_wrong_amount".to_string(),
+             printer.clone(),
+             param,
+             get_automaton(),
+             data_trace(vec![("a",vec![3]),("b",vec![3,0]),("c",vec![3])]));
+    assert_eq!((verdict.word_verdict.deviations,verdict.get_violations(&NfaDataViolation::GuardViolation),verdict.word_verdict.unexpected_letters), (2,1,1));
+}
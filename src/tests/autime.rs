@@ -0,0 +1,116 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use maplit::{btreemap,btreeset};
+
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisPolicy, NfaWordAnalysisResetOn};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autime::automaton::NfaTimedAutomaton;
+use crate::autime::guard::{NfaDelayGuard, NfaDelayReference};
+use crate::autime::semantics::NfaTimedViolation;
+
+use crate::tests::harness::conf_test;
+use crate::tests::nfa::get_rv_nfa;
+
+
+#[test]
+fn tests_timed() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // "b" within 5 after "a" and "c" within 10 after the "a" which started the cycle
+    let get_automaton = || NfaTimedAutomaton::new(nfa.clone())
+        .with_guard((1,letter("b"),2),NfaDelayGuard::new(NfaDelayReference::PreviousEvent,None,Some(5)))
+        .with_clock_reset((0,letter("a"),1),"x".to_string())
+        .with_guard((2,letter("c"),0),NfaDelayGuard::new(NfaDelayReference::Clock("x".to_string()),None,Some(10)));
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let timed_trace = |events : Vec<(&str,u64)>| events.into_iter().map(|(x,t)| (letter(x),t)).collect();
+    let verdict = conf_test("timed_ok".to_string(),
+             printer.clone(),
+             param.clone(),
+             get_automaton(),
+             timed_trace(vec![("a",0),("b",3),("c",8)]));
+    assert_eq!(verdict.word_verdict, NfaWordAnalysisGlobalVerdict::new(0,true));
+    assert!(verdict.violations.is_empty());
+    // "b" occurs too late hence it is skipped and "c" becomes unexpected
+    let verdict = conf_test("timed_late_b".to_string(),
+             printer.clone(),
+             param.clone(),
+             get_automaton(),
+             timed_trace(vec![("a",0),("b",7),("c",9)]));
+    assert_eq!((verdict.word_verdict.deviations,verdict.get_violations(&NfaTimedViolation::DelayViolation),verdict.word_verdict.unexpected_letters), (2,1,1));
+    // "c" occurs too late w.r.t. the clock reset when reading "a"
+    let verdict = conf_test("timed_late_c".to_string(),
+             printer.clone(),
+             param.clone(),
+             get_automaton(),
+             timed_trace(vec![("a",0),("b",3),("c",12)]));
+    assert_eq!((verdict.word_verdict.deviations,verdict.get_violations(&NfaTimedViolation::DelayViolation)), (1,1));
+    // "c" is timestamped before "b" hence it is skipped and the word ends prematurely
+    let verdict = conf_test("timed_non_monotonic".to_string(),
+             printer.clone(),
+             param.clone(),
+             get_automaton(),
+             timed_trace(vec![("a",0),("b",3),("c",2)]));
+    assert_eq!((verdict.word_verdict.deviations,
+                verdict.get_violations(&NfaTimedViolation::NonMonotonicTimestamp),
+                verdict.get_violations(&NfaTimedViolation::DelayViolation),
+                verdict.word_verdict.premature_ends), (1,1,0,1));
+    // a clock which is never reset measures the delay since the beginning of the run
+    // hence the first "c" is on time but not the second one
+    let get_never_reset = || NfaTimedAutomaton::new(nfa.clone())
+        .with_guard((2,letter("c"),0),NfaDelayGuard::new(NfaDelayReference::Clock("y".to_string()),None,Some(10)));
+    let verdict = conf_test("timed_never_reset".to_string(),
+             printer.clone(),
+             param,
+             get_never_reset(),
+             timed_trace(vec![("a",4),("b",6),("c",14),("a",15),("b",16),("c",17)]));
+    assert_eq!((verdict.word_verdict.deviations,verdict.get_violations(&NfaTimedViolation::DelayViolation)), (1,1));
+    // resetting starts a new run hence the clock measures the delay since the reset
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(Some(NfaWordAnalysisResetOn::Initials)));
+    let verdict = conf_test("timed_never_reset_after_reset".to_string(),
+             printer.clone(),
+             param,
+             get_never_reset(),
+             timed_trace(vec![("a",0),("c",1),("a",20),("b",21),("c",25)]));
+    assert_eq!((verdict.word_verdict.deviations,verdict.word_verdict.unexpected_letters,verdict.word_verdict.emptied_trace), (1,1,true));
+    assert!(verdict.violations.is_empty());
+    // configurations and events are drawn with the time elapsed since the clock resets and the previous event
+    let word : Vec<(usize,u64)> = timed_trace(vec![("a",2),("b",5),("c",9)]);
+    let configurations = btreeset!{(2,btreemap!{"x".to_string() => 2})};
+    let automaton = get_automaton();
+    assert_eq!(automaton.configurations_to_string(&configurations,&word,2), "{S2[x=+7]}");
+    assert_eq!(automaton.configurations_to_string(&configurations,&word,3), "{S2[x=+7]}");
+    assert_eq!((0..3).map(|pos| automaton.event_to_string(&word,pos,&printer)).collect::<Vec<String>>(),
+               vec!["a".to_string(),"b+3".to_string(),"c+4".to_string()]);
+}
+
+
+#[test]
+#[should_panic(expected = "events cannot be swapped in the analysis of configurations")]
+fn tests_timed_swaps_rejected() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None)).with_max_swap_distance(1);
+    let word = vec![(letter("a"),0),(letter("c"),1),(letter("b"),2)];
+    conf_test("timed_swaps".to_string(),printer,param,NfaTimedAutomaton::new(nfa),word);
+}
@@ -0,0 +1,79 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use maplit::{hashmap,hashset};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autlang::check::check_language_conformance;
+use crate::autlang::param::NfaLanguageConformanceQuantifier;
+use crate::autlang::verdict::NfaLanguageConformanceVerdict;
+
+use crate::tests::nfa::{get_rv_nfa, get_eps_nfa, get_words_nfa};
+
+
+#[test]
+fn tests_language() {
+    let (printer,nfa) = get_rv_nfa();
+    let letters = |xs : Vec<&str>| -> Vec<usize> {
+        xs.into_iter().map(|x| printer.map.iter().position(|y| y == x).unwrap()).collect()
+    };
+    let conforming = get_words_nfa(&printer,vec![vec!["a","b","c"],vec!["a","a"]]);
+    assert_eq!(check_language_conformance(&nfa,&conforming,&NfaLanguageConformanceQuantifier::Every),
+               NfaLanguageConformanceVerdict::Conforms(None));
+    // "a.b" does not conform but "a.b.c" does
+    let partial = get_words_nfa(&printer,vec![vec!["a","b","c"],vec!["a","b"]]);
+    assert_eq!(check_language_conformance(&nfa,&partial,&NfaLanguageConformanceQuantifier::Every),
+               NfaLanguageConformanceVerdict::DoesNotConform(Some(letters(vec!["a","b"]))));
+    assert_eq!(check_language_conformance(&nfa,&partial,&NfaLanguageConformanceQuantifier::Some),
+               NfaLanguageConformanceVerdict::Conforms(Some(letters(vec!["a","b","c"]))));
+    let violating = get_words_nfa(&printer,vec![vec!["c","c"],vec!["b"]]);
+    assert_eq!(check_language_conformance(&nfa,&violating,&NfaLanguageConformanceQuantifier::Some),
+               NfaLanguageConformanceVerdict::DoesNotConform(Some(letters(vec!["b"]))));
+}
+
+
+#[test]
+fn tests_language_epsilon() {
+    let (printer,eps_nfa) = get_eps_nfa();
+    let (_,rv_nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let letters = |xs : Vec<&str>| -> Vec<usize> { xs.into_iter().map(letter).collect() };
+    // the epsilon transition of the specification allows "c" right after "a"
+    let conforming = get_words_nfa(&printer,vec![vec!["a","c"],vec!["a","b","c"]]);
+    assert_eq!(check_language_conformance(&eps_nfa,&conforming,&NfaLanguageConformanceQuantifier::Every),
+               NfaLanguageConformanceVerdict::Conforms(None));
+    let partial = get_words_nfa(&printer,vec![vec!["a","c"],vec!["a"]]);
+    assert_eq!(check_language_conformance(&eps_nfa,&partial,&NfaLanguageConformanceQuantifier::Every),
+               NfaLanguageConformanceVerdict::DoesNotConform(Some(letters(vec!["a"]))));
+    // the trace NFA accepts "a" and "a.b.c" only through its epsilon transition
+    let mut transitions = vec![hashmap!{};5];
+    transitions[0].insert(letter("a"), hashset!{1});
+    transitions[2].insert(letter("b"), hashset!{3});
+    transitions[3].insert(letter("c"), hashset!{4});
+    let epsilon_trans = vec![hashset!{},hashset!{2},hashset!{},hashset!{},hashset!{}];
+    let trace = AutNFAIT::<usize>::from_raw(eps_nfa.alphabet.clone(),
+                                            hashset!{0}, // initials
+                                            hashset!{2,4}, // finals
+                                            transitions,
+                                            epsilon_trans).unwrap();
+    assert_eq!(check_language_conformance(&rv_nfa,&trace,&NfaLanguageConformanceQuantifier::Every),
+               NfaLanguageConformanceVerdict::Conforms(None));
+    assert_eq!(check_language_conformance(&eps_nfa,&trace,&NfaLanguageConformanceQuantifier::Every),
+               NfaLanguageConformanceVerdict::DoesNotConform(Some(letters(vec!["a"]))));
+    assert_eq!(check_language_conformance(&eps_nfa,&trace,&NfaLanguageConformanceQuantifier::Some),
+               NfaLanguageConformanceVerdict::Conforms(Some(letters(vec!["a","b","c"]))));
+}
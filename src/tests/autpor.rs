@@ -0,0 +1,99 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use maplit::{hashset,hashmap};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autpor::independence::NfaIndependenceRelation;
+use crate::autpor::membership::analyze_trace;
+
+use crate::tests::nfa::{get_rv_nfa, get_eps_nfa};
+
+
+#[test]
+fn tests_partial_order() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let word = vec![letter("a"),letter("c"),letter("b")];
+    // "b" and "c" are totally ordered
+    let membership = analyze_trace(&nfa,&word,&NfaIndependenceRelation::default());
+    assert!(!membership.is_accepted());
+    // "b" and "c" come from different threads
+    let independence = NfaIndependenceRelation::default().with_independent(letter("b"),letter("c"));
+    let membership = analyze_trace(&nfa,&word,&independence);
+    assert_eq!(membership.linearization, Some(vec![0,2,1]));
+    assert_eq!(membership.get_linearized_word(&word), Some(vec![letter("a"),letter("b"),letter("c")]));
+    // a word which is accepted as is keeps its order
+    let word = vec![letter("a"),letter("b"),letter("c")];
+    assert_eq!(analyze_trace(&nfa,&word,&independence).linearization, Some(vec![0,1,2]));
+    // all the "a" must precede all the "b" whereas the word interleaves them
+    let transitions = vec![hashmap!{letter("a") => hashset!{0}, letter("b") => hashset!{1}},
+                           hashmap!{letter("b") => hashset!{1}}];
+    let sorted = AutNFAIT::<usize>::from_raw((0..printer.map.len()).collect(),
+                                             hashset!{0},
+                                             hashset!{0,1},
+                                             transitions,
+                                             vec![hashset!{};2]).unwrap();
+    let word : Vec<usize> = (0..8).flat_map(|_| vec![letter("b"),letter("a")]).collect();
+    let independence = NfaIndependenceRelation::default().with_independent(letter("a"),letter("b"));
+    let membership = analyze_trace(&sorted,&word,&independence);
+    assert!(membership.is_accepted());
+    // at most one configuration per prefix of each thread and NFA state
+    // whereas there are 12870 interleavings
+    assert!(membership.explored <= 9 * 9 * 2);
+    // six pairwise independent letters occurring three times each are never accepted
+    // hence all the prefixes are explored, i.e. 4^6 of them
+    // whereas there are 18!/(3!^6) interleavings
+    let letters : Vec<usize> = (0..printer.map.len()).collect();
+    let looping = AutNFAIT::<usize>::from_raw(letters.iter().cloned().collect(),
+                                              hashset!{0},
+                                              hashset!{},
+                                              vec![letters.iter().map(|l| (*l,hashset!{0})).collect()],
+                                              vec![hashset!{}]).unwrap();
+    let word : Vec<usize> = (0..3).flat_map(|_| letters.clone()).collect();
+    let mut independence = NfaIndependenceRelation::default();
+    for letter1 in &letters {
+        for letter2 in &letters {
+            independence = independence.with_independent(*letter1,*letter2);
+        }
+    }
+    let membership = analyze_trace(&looping,&word,&independence);
+    assert!(!membership.is_accepted());
+    assert_eq!(membership.explored, 4usize.pow(6));
+}
+
+
+#[test]
+fn tests_partial_order_epsilon() {
+    let (printer,nfa) = get_eps_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // "c" may directly follow "a" through the epsilon transition
+    let word = vec![letter("c"),letter("a")];
+    assert!(!analyze_trace(&nfa,&word,&NfaIndependenceRelation::default()).is_accepted());
+    let independence = NfaIndependenceRelation::default().with_independent(letter("a"),letter("c"));
+    let membership = analyze_trace(&nfa,&word,&independence);
+    assert_eq!(membership.get_linearized_word(&word), Some(vec![letter("a"),letter("c")]));
+    // the epsilon transition allows reading "c" right after "a" but "b" cannot be read afterwards
+    // hence "b" and "c" are reordered
+    let word = vec![letter("a"),letter("c"),letter("b")];
+    let independence = NfaIndependenceRelation::default().with_independent(letter("b"),letter("c"));
+    let membership = analyze_trace(&nfa,&word,&independence);
+    assert_eq!(membership.linearization, Some(vec![0,2,1]));
+    // the word ends in the middle of the cycle, whatever the epsilon transition
+    let word = vec![letter("a")];
+    assert!(!analyze_trace(&nfa,&word,&independence).is_accepted());
+}
@@ -0,0 +1,100 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeMap;
+use maplit::{btreemap,hashset,hashmap};
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autprob::count::count_runs;
+use crate::autprob::scores::get_word_score;
+use crate::autprob::semiring::{NfaProbabilityWeight, NfaViterbiWeight};
+use crate::autprob::table::NfaWeightTable;
+use crate::autprob::viterbi::get_best_run;
+
+use crate::tests::nfa::{get_rv_nfa, get_words_nfa};
+
+
+#[test]
+fn tests_probabilistic() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // the classifier hesitates between "b" and "a" and then between "c" and "a"
+    let distributions = |weights : Vec<Vec<(&str,f64)>>| -> Vec<BTreeMap<usize,f64>> {
+        weights.into_iter().map(|pos| pos.into_iter().map(|(x,p)| (letter(x),p)).collect()).collect()
+    };
+    let word = distributions(vec![vec![("a",1.0)],vec![("b",0.6),("a",0.4)],vec![("c",0.7),("a",0.3)]]);
+    let viterbi_word : Vec<BTreeMap<usize,NfaViterbiWeight>> = word.iter()
+        .map(|pos| pos.iter().map(|(l,p)| (*l,NfaViterbiWeight(*p))).collect()).collect();
+    let probability_word : Vec<BTreeMap<usize,NfaProbabilityWeight>> = word.iter()
+        .map(|pos| pos.iter().map(|(l,p)| (*l,NfaProbabilityWeight(*p))).collect()).collect();
+    // after "a" the NFA loops or moves on with equal probability
+    let viterbi_table = NfaWeightTable::default()
+        .with_weight((0,letter("a"),0),NfaViterbiWeight(0.5))
+        .with_weight((0,letter("a"),1),NfaViterbiWeight(0.5));
+    let probability_table = NfaWeightTable::default()
+        .with_weight((0,letter("a"),0),NfaProbabilityWeight(0.5))
+        .with_weight((0,letter("a"),1),NfaProbabilityWeight(0.5));
+    let run = get_best_run(&nfa,&viterbi_table,&viterbi_word).unwrap();
+    assert_eq!((run.states,run.letters), (vec![0,1,2,0],vec![letter("a"),letter("b"),letter("c")]));
+    assert!((run.score.0 - 0.21).abs() < 1e-9);
+    assert!((get_word_score(&nfa,&viterbi_table,&viterbi_word).0 - 0.21).abs() < 1e-9);
+    // the run looping on "a" adds 0.5*0.4*0.5*0.3*0.5
+    assert!((get_word_score(&nfa,&probability_table,&probability_word).0 - 0.225).abs() < 1e-9);
+    // no accepting run
+    let rejected : Vec<BTreeMap<usize,NfaViterbiWeight>> = vec![btreemap!{letter("b") => NfaViterbiWeight(1.0)}];
+    assert_eq!(get_best_run(&nfa,&viterbi_table,&rejected), None);
+    assert_eq!(get_word_score(&nfa,&viterbi_table,&rejected), NfaViterbiWeight(0.0));
+}
+
+
+#[test]
+fn tests_run_counting() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let report = count_runs(&nfa,&[letter("a"),letter("b"),letter("c")]);
+    assert_eq!((report.accepting_runs,report.is_ambiguous(),report.first_merge), (1,false,None));
+    // both branches read "a.b" and remain apart
+    let duplicated = get_words_nfa(&printer,vec![vec!["a","b"],vec!["a","b"]]);
+    let report = count_runs(&duplicated,&[letter("a"),letter("b")]);
+    assert_eq!((report.accepting_runs,report.is_ambiguous(),report.first_merge), (2,true,None));
+    // both branches read "a" and merge when reading "b"
+    let transitions = vec![hashmap!{letter("a") => hashset!{1,2}},
+                           hashmap!{letter("b") => hashset!{3}},
+                           hashmap!{letter("b") => hashset!{3}},
+                           hashmap!{}];
+    let merging = AutNFAIT::<usize>::from_raw((0..printer.map.len()).collect(),
+                                              hashset!{0},
+                                              hashset!{3},
+                                              transitions,
+                                              vec![hashset!{};4]).unwrap();
+    let report = count_runs(&merging,&[letter("a"),letter("b")]);
+    assert_eq!((report.accepting_runs,report.final_counts,report.first_merge), (2,btreemap!{3 => 2},Some(2)));
+    // two runs merging in a state from which no final state is reachable do not count as a merge
+    let transitions = vec![hashmap!{letter("a") => hashset!{1,2,3}},
+                           hashmap!{letter("b") => hashset!{5}},
+                           hashmap!{letter("b") => hashset!{5}},
+                           hashmap!{letter("b") => hashset!{4}},
+                           hashmap!{},
+                           hashmap!{}];
+    let dead_merge = AutNFAIT::<usize>::from_raw((0..printer.map.len()).collect(),
+                                                 hashset!{0},
+                                                 hashset!{4},
+                                                 transitions,
+                                                 vec![hashset!{};6]).unwrap();
+    let report = count_runs(&dead_merge,&[letter("a"),letter("b")]);
+    assert_eq!((report.accepting_runs,report.final_counts,report.first_merge), (1,btreemap!{4 => 1,5 => 2},None));
+}
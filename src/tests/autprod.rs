@@ -0,0 +1,78 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use maplit::{hashset,hashmap};
+
+use autour_core::nfa::nfa::AutNFA;
+
+use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisPolicy, NfaWordAnalysisResetOn};
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autprod::automaton::NfaProductAutomaton;
+
+use crate::tests::harness::conf_test;
+use crate::tests::printer::TestNFAPrinter;
+
+
+/// Two components synchronizing on "b": the first one reads (a.b)* and the second one (b.c)*.
+fn get_product_components() -> (TestNFAPrinter,Vec<AutNFA<usize>>) {
+    let printer = TestNFAPrinter::get_printer();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let mut transitions_ab = vec![hashmap!{};2];
+    transitions_ab[0].insert(letter("a"), hashset!{1});
+    transitions_ab[1].insert(letter("b"), hashset!{0});
+    let nfa_ab = AutNFA::<usize>::from_raw(hashset!{letter("a"),letter("b")},
+                                            hashset!{0},
+                                            hashset!{0},
+                                            transitions_ab).unwrap();
+    let mut transitions_bc = vec![hashmap!{};2];
+    transitions_bc[0].insert(letter("b"), hashset!{1});
+    transitions_bc[1].insert(letter("c"), hashset!{0});
+    let nfa_bc = AutNFA::<usize>::from_raw(hashset!{letter("b"),letter("c")},
+                                            hashset!{0},
+                                            hashset!{0},
+                                            transitions_bc).unwrap();
+    (printer,vec![nfa_ab,nfa_bc])
+}
+
+
+#[test]
+fn tests_product() {
+    let (printer,components) = get_product_components();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let param = NfaWordAnalysisParameterization::new(
+        NfaWordAnalysisResetOn::Initials,
+        NfaWordAnalysisPolicy::SkipAndMayReset(None));
+    let verdict = conf_test("product_accepted".to_string(),
+             printer.clone(),
+             param.clone(),
+             NfaProductAutomaton::new(components.clone()),
+             vec!["a","b","a","c","b","c"].into_iter().map(letter).collect());
+    assert_eq!(verdict.word_verdict, NfaWordAnalysisGlobalVerdict::new(0,true));
+    // "c" cannot be read before the components synchronize on "b"
+    let verdict = conf_test("product_unexpected".to_string(),
+             printer.clone(),
+             param.clone(),
+             NfaProductAutomaton::new(components.clone()),
+             vec!["a","c","b","c"].into_iter().map(letter).collect());
+    assert_eq!((verdict.word_verdict.deviations,verdict.word_verdict.unexpected_letters,verdict.word_verdict.emptied_trace), (1,1,true));
+    // "d" is in the alphabet of no component and the first component does not end in a final state
+    let verdict = conf_test("product_unknown".to_string(),
+             printer.clone(),
+             param,
+             NfaProductAutomaton::new(components),
+             vec!["a","b","d","a"].into_iter().map(letter).collect());
+    assert_eq!((verdict.word_verdict.unknown_letters,verdict.word_verdict.premature_ends), (1,1));
+}
@@ -0,0 +1,84 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::autrep::param::{NfaWordRepairCosts, NfaWordRepairParameterization};
+use crate::autrep::priorities::NfaWordRepairPriorities;
+use crate::autrep::search::get_minimal_repair;
+use crate::autrep::step::NfaWordRepairStepKind;
+
+use crate::tests::harness::rep_test;
+use crate::tests::nfa::get_rv_nfa;
+
+
+#[test]
+fn tests_repair() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // an accepted word needs no edits
+    let (verdict,repair) = rep_test("repair_accepted".to_string(),
+             printer.clone(),
+             NfaWordRepairParameterization::new(NfaWordRepairCosts::default()),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "b".to_string(),
+                 "c".to_string()
+             ]);
+    assert_eq!(verdict.min_cost, Some(0));
+    assert!(repair.unwrap().get_edits().is_empty());
+    // a duplicated letter is deleted
+    let word = vec![letter("a"),letter("b"),letter("b"),letter("c")];
+    let (verdict,repair) = rep_test("repair_delete".to_string(),
+             printer.clone(),
+             NfaWordRepairParameterization::new(NfaWordRepairCosts::default()),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "b".to_string(),
+                 "b".to_string(),
+                 "c".to_string()
+             ]);
+    assert_eq!(verdict.min_cost, Some(1));
+    let repair = repair.unwrap();
+    assert_eq!(repair.get_repaired_word(&word), vec![letter("a"),letter("b"),letter("c")]);
+    assert_eq!(repair.alignment_to_string(&word,&printer).lines().count(), 2);
+    // a missing letter is inserted, unless deleting is cheaper
+    let word = vec![letter("a"),letter("b"),letter("a")];
+    let (verdict,repair) = rep_test("repair_insert".to_string(),
+             printer.clone(),
+             NfaWordRepairParameterization::new(NfaWordRepairCosts::new(1,3,3)),
+             nfa.clone(),
+             vec![
+                 "a".to_string(),
+                 "b".to_string(),
+                 "a".to_string()
+             ]);
+    assert_eq!(verdict.min_cost, Some(1));
+    let repair = repair.unwrap();
+    assert_eq!(repair.get_repaired_word(&word), vec![letter("a"),letter("b"),letter("c"),letter("a")]);
+    assert_eq!(repair.alignment_to_string(&word,&printer), "a b - a\na b c a".to_string());
+    // the cost-ordered search finds the same minimal repair
+    let minimal = get_minimal_repair(&nfa,&word,&NfaWordRepairCosts::new(1,3,3)).unwrap();
+    assert_eq!(minimal.cost, 1);
+    assert_eq!(minimal.get_repaired_word(&word), vec![letter("a"),letter("b"),letter("c"),letter("a")]);
+    // several cheap edits are preferred to a single expensive one
+    let minimal = get_minimal_repair(&nfa,&word,&NfaWordRepairCosts::new(10,1,10)).unwrap();
+    assert_eq!(minimal.cost, 1);
+    assert_eq!(minimal.get_edits(), vec![&NfaWordRepairStepKind::Delete]);
+    // large costs do not wrap around when used as priorities
+    let priorities = NfaWordRepairPriorities::from_costs(&NfaWordRepairCosts::new(u32::MAX,1,(i32::MAX as u32) + 1));
+    assert_eq!((priorities.insertion,priorities.deletion,priorities.substitution), (i32::MIN,-1,i32::MIN));
+}
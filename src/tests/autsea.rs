@@ -0,0 +1,67 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::autsea::matching::{highlight_matches, NfaWordMatch};
+use crate::autsea::param::{NfaWordSearchMode, NfaWordSearchParameterization};
+use crate::autsea::search::search_matches;
+
+use crate::tests::nfa::{get_rv_nfa, get_eps_nfa};
+
+
+#[test]
+fn tests_search() {
+    let (printer,nfa) = get_rv_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    let word : Vec<usize> = ["d","a","b","c","d","b","c","a"].iter().map(|x| letter(x)).collect();
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::AllMatches,0));
+    assert_eq!(matches,
+               vec![NfaWordMatch::new(1,2,0),
+                    NfaWordMatch::new(1,4,0),
+                    NfaWordMatch::new(7,8,0)]);
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::LeftmostLongest,0));
+    assert_eq!(highlight_matches(&word,&matches,&printer), "d.[a.b.c].d.b.c.[a]".to_string());
+    // with one edit per match, each 'd' can be deleted or substituted by an 'a'
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::LeftmostLongest,1));
+    assert_eq!(matches, vec![NfaWordMatch::new(0,4,1),NfaWordMatch::new(4,8,1)]);
+    // all the factors which are one edit away from the language, with the fewest edits for each
+    let word : Vec<usize> = ["b","c"].iter().map(|x| letter(x)).collect();
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::AllMatches,1));
+    // "b" is deleted or substituted by an "a", "b.c" misses its "a" and "c" is deleted or substituted
+    assert_eq!(matches,
+               vec![NfaWordMatch::new(0,1,1),
+                    NfaWordMatch::new(0,2,1),
+                    NfaWordMatch::new(1,2,1)]);
+}
+
+
+#[test]
+fn tests_search_epsilon() {
+    let (printer,nfa) = get_eps_nfa();
+    let letter = |x : &str| printer.map.iter().position(|y| y == x).unwrap();
+    // "c" may directly follow "a" through the epsilon transition
+    let word : Vec<usize> = ["a","c","a","b","c"].iter().map(|x| letter(x)).collect();
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::AllMatches,0));
+    assert_eq!(matches,
+               vec![NfaWordMatch::new(0,2,0),
+                    NfaWordMatch::new(0,5,0),
+                    NfaWordMatch::new(2,5,0)]);
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::LeftmostLongest,0));
+    assert_eq!(highlight_matches(&word,&matches,&printer), "[a.c.a.b.c]".to_string());
+    // inserting "a" is enough for "c" to match
+    let word : Vec<usize> = ["c"].iter().map(|x| letter(x)).collect();
+    let matches = search_matches(&nfa,&word,&NfaWordSearchParameterization::new(NfaWordSearchMode::AllMatches,1));
+    assert_eq!(matches, vec![NfaWordMatch::new(0,1,1)]);
+}
@@ -0,0 +1,190 @@
+/*
+Copyright 2023 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::path::PathBuf;
+
+use graphviz_dot_builder::traits::GraphVizOutputFormat;
+use graph_process_manager_loggers::graphviz::drawer::GraphVizProcessDrawer;
+use graph_process_manager_loggers::graphviz::format::GraphVizProcessLoggerLayout;
+use graph_process_manager_loggers::graphviz::logger::GenericGraphVizLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::manager::manager::GenericProcessManager;
+
+use autour_core::nfait::nfait::AutNFAIT;
+
+use crate::autana::conf::NfaWordAnalysisConfig;
+use crate::autana::context::NfaWordAnalysisContext;
+use crate::autana::loggers::glog::drawer::NfaWordAnalysisProcessDrawer;
+use crate::autana::loggers::plog::logger::NfaWordAnalysisPathsLogger;
+use crate::autana::loggers::plog::path::NfaWordAnalysisPath;
+use crate::autana::observation::NfaWordAnalysisObservation;
+use crate::autana::param::NfaWordAnalysisParameterization;
+use crate::autana::priorities::NfaWordAnalysisPriorities;
+use crate::autana::run::get_path_verdict;
+use crate::autana::verdict::global::NfaWordAnalysisGlobalVerdict;
+use crate::autconf::conf::NfaConfigurationAnalysisConfig;
+use crate::autconf::context::NfaConfigurationAnalysisContext;
+use crate::autconf::loggers::glog::drawer::NfaConfigurationAnalysisProcessDrawer;
+use crate::autconf::semantics::NfaConfigurationSemantics;
+use crate::autconf::verdict::global::NfaConfigurationAnalysisGlobalVerdict;
+use crate::autrep::conf::NfaWordRepairConfig;
+use crate::autrep::context::NfaWordRepairContext;
+use crate::autrep::loggers::glog::drawer::NfaWordRepairProcessDrawer;
+use crate::autrep::loggers::rlog::logger::NfaWordRepairsLogger;
+use crate::autrep::param::NfaWordRepairParameterization;
+use crate::autrep::priorities::NfaWordRepairPriorities;
+use crate::autrep::loggers::rlog::repair::NfaWordRepair;
+use crate::autrep::verdict::global::NfaWordRepairGlobalVerdict;
+use crate::tests::printer::TestNFAPrinter;
+
+
+/// Folder in which the processes are drawn, and folder for the temporary files of the drawers.
+fn get_output_folders() -> (String,String) {
+    let root : PathBuf = std::env::temp_dir().join("autour_process");
+    let output = root.join("test").into_os_string().into_string().unwrap();
+    let temp = root.join("test_temp").into_os_string().into_string().unwrap();
+    (output,temp)
+}
+
+pub fn get_word(printer : &TestNFAPrinter, trace : &[String]) -> Vec<usize> {
+    trace.iter().map(|x| printer.map.iter().position(|y| y == x).unwrap()).collect()
+}
+
+/// Draws a process in the test output folder.
+pub fn make_graphic_logger<Conf : 'static + AbstractProcessConfiguration>(output_name : String,
+                                                                          make_drawer : impl FnOnce(String) -> Box<dyn GraphVizProcessDrawer<Conf>>)
+            -> Box<dyn AbstractProcessLogger<Conf>> {
+    let (output_folder,temp_folder) = get_output_folders();
+    let graphic_logger : GenericGraphVizLogger<Conf> = GenericGraphVizLogger::new(
+        make_drawer(temp_folder),
+        GraphVizOutputFormat::svg,
+        GraphVizProcessLoggerLayout::Vertical,
+        true,
+        output_folder,
+        output_name);
+    Box::new(graphic_logger)
+}
+
+/// Runs any process with a breadth first search.
+/// The manager is returned so that its loggers can be queried.
+pub fn run_process<Conf : 'static + AbstractProcessConfiguration>(context : Conf::Context,
+                                                                  param : Conf::Parameterization,
+                                                                  init_node : Conf::NodeKind,
+                                                                  priorities : Conf::Priorities,
+                                                                  loggers : Vec<Box<dyn AbstractProcessLogger<Conf>>>,
+                                                                  is_memoized : bool) -> (Conf::GlobalVerdict,GenericProcessManager<Conf>) {
+    let delegate = GenericProcessDelegate::new(QueueSearchStrategy::BFS,
+                                               GenericProcessPriorities::new(priorities,false));
+    let mut manager : GenericProcessManager<Conf> = GenericProcessManager::new(context,
+                                                                               param,
+                                                                               delegate,
+                                                                               vec![],
+                                                                               loggers,
+                                                                               None,
+                                                                               is_memoized);
+    let (_, verdict) = manager.start_process(init_node);
+    (verdict,manager)
+}
+
+pub fn ana_test(output_name : String,
+                printer : TestNFAPrinter,
+                param : NfaWordAnalysisParameterization,
+                nfa : AutNFAIT<usize>,
+                trace : Vec<String>) -> NfaWordAnalysisGlobalVerdict {
+    let (verdict,_) = ana_test_paths(output_name,printer,param,nfa,trace);
+    verdict
+}
+
+pub fn ana_test_paths(output_name : String,
+                      printer : TestNFAPrinter,
+                      param : NfaWordAnalysisParameterization,
+                      nfa : AutNFAIT<usize>,
+                      trace : Vec<String>) -> (NfaWordAnalysisGlobalVerdict,Vec<NfaWordAnalysisPath>) {
+    let word = get_word(&printer,&trace).into_iter().map(NfaWordAnalysisObservation::Letter).collect();
+    ana_test_observations(output_name,printer,param,nfa,word)
+}
+
+/// Returns the metrics of the best path rather than the global verdict, which sums those of all the branches.
+/// The paths logger is the second logger, after the graphic one.
+pub fn ana_test_observations(output_name : String,
+                             printer : TestNFAPrinter,
+                             param : NfaWordAnalysisParameterization,
+                             nfa : AutNFAIT<usize>,
+                             word : Vec<NfaWordAnalysisObservation>) -> (NfaWordAnalysisGlobalVerdict,Vec<NfaWordAnalysisPath>) {
+    let context : NfaWordAnalysisContext<TestNFAPrinter> = NfaWordAnalysisContext::from_observations(nfa,printer,word);
+    let init_node = context.make_init_node(&param);
+    let graphic_logger = make_graphic_logger(format!("proc_{}",output_name),
+                                             |temp_folder| Box::new(NfaWordAnalysisProcessDrawer::new(temp_folder)));
+    let (_,manager) = run_process::<NfaWordAnalysisConfig<TestNFAPrinter>>(
+        context,
+        param,
+        init_node,
+        NfaWordAnalysisPriorities::default(),
+        vec![graphic_logger,Box::new(NfaWordAnalysisPathsLogger::new(2))],
+        false);
+    let paths_logger = manager.get_logger(1).unwrap()
+        .as_any().downcast_ref::<NfaWordAnalysisPathsLogger>().unwrap();
+    (get_path_verdict(&paths_logger.get_best_path().cloned()),paths_logger.paths.clone())
+}
+
+/// Memoization is required for the repair process to terminate.
+pub fn rep_test(output_name : String,
+                printer : TestNFAPrinter,
+                param : NfaWordRepairParameterization,
+                nfa : AutNFAIT<usize>,
+                trace : Vec<String>) -> (NfaWordRepairGlobalVerdict,Option<NfaWordRepair>) {
+    let word = get_word(&printer,&trace);
+    let context : NfaWordRepairContext<TestNFAPrinter> = NfaWordRepairContext::from_nfait(nfa,printer,word);
+    let init_node = param.make_init_node(&context.nfa);
+    let priorities = NfaWordRepairPriorities::from_costs(&param.costs);
+    let graphic_logger = make_graphic_logger(format!("rep_{}",output_name),
+                                             |temp_folder| Box::new(NfaWordRepairProcessDrawer::new(temp_folder)));
+    let (verdict,manager) = run_process::<NfaWordRepairConfig<TestNFAPrinter>>(
+        context,
+        param,
+        init_node,
+        priorities,
+        vec![graphic_logger,Box::new(NfaWordRepairsLogger::new())],
+        true);
+    let repairs_logger = manager.get_logger(1).unwrap()
+        .as_any().downcast_ref::<NfaWordRepairsLogger>().unwrap();
+    (verdict,repairs_logger.get_best_repair().cloned())
+}
+
+/// Analyzes a word of events against any automaton with a configuration-based semantics.
+pub fn conf_test<Semantics : 'static + NfaConfigurationSemantics>(output_name : String,
+                                                                  printer : TestNFAPrinter,
+                                                                  param : NfaWordAnalysisParameterization,
+                                                                  semantics : Semantics,
+                                                                  word : Vec<Semantics::Event>)
+            -> NfaConfigurationAnalysisGlobalVerdict<Semantics::Violation> {
+    let context : NfaConfigurationAnalysisContext<Semantics,TestNFAPrinter> = NfaConfigurationAnalysisContext::new(semantics,printer,word);
+    let init_node = context.make_init_node(&param);
+    let graphic_logger = make_graphic_logger(output_name,
+                                             |temp_folder| Box::new(NfaConfigurationAnalysisProcessDrawer::new(temp_folder)));
+    let (verdict,_) = run_process::<NfaConfigurationAnalysisConfig<Semantics,TestNFAPrinter>>(
+        context,
+        param,
+        init_node,
+        NfaWordAnalysisPriorities::default(),
+        vec![graphic_logger],
+        false);
+    verdict
+}
@@ -16,5 +16,15 @@ limitations under the License.
 
 
 pub mod printer;
+pub mod harness;
 pub mod nfa;
-pub mod ana;
\ No newline at end of file
+pub mod autana;
+pub mod autrep;
+pub mod autali;
+pub mod autsea;
+pub mod autprod;
+pub mod autime;
+pub mod autdata;
+pub mod autlang;
+pub mod autprob;
+pub mod autpor;
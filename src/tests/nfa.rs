@@ -18,12 +18,13 @@ use std::collections::HashSet;
 use maplit::{hashset,hashmap};
 
 use autour_core::nfa::nfa::AutNFA;
-use crate::autana::param::{NfaWordAnalysisParameterization, NfaWordAnalysisPolicy, NfaWordAnalysisResetOn};
-use crate::tests::ana::ana_test;
+use autour_core::nfait::nfait::AutNFAIT;
+use autour_core::traits::translate::AutTranslatable;
 
 use crate::tests::printer::TestNFAPrinter;
 
-fn get_rv_nfa() -> (TestNFAPrinter,AutNFA<usize>) {
+
+pub fn get_rv_nfa() -> (TestNFAPrinter,AutNFAIT<usize>) {
     let printer = TestNFAPrinter::get_printer();
     let alphabet : HashSet<usize> = (0..printer.map.len()).collect();
     let mut transitions = vec![hashmap!{};3];
@@ -34,73 +35,59 @@ fn get_rv_nfa() -> (TestNFAPrinter,AutNFA<usize>) {
                                          hashset!{0}, // initials
                                          hashset!{0}, // finals
                                          transitions).unwrap();
+    (printer,nfa.to_nfait())
+}
+
+
+pub fn get_eps_nfa() -> (TestNFAPrinter,AutNFAIT<usize>) {
+    let printer = TestNFAPrinter::get_printer();
+    let alphabet : HashSet<usize> = (0..printer.map.len()).collect();
+    let mut transitions = vec![hashmap!{};3];
+    transitions[0].insert(printer.map.iter().position(|x| x == "a").unwrap(), hashset!{1});
+    transitions[1].insert(printer.map.iter().position(|x| x == "b").unwrap(), hashset!{2});
+    transitions[2].insert(printer.map.iter().position(|x| x == "c").unwrap(), hashset!{0});
+    let epsilon_trans = vec![hashset!{},hashset!{2},hashset!{}];
+    let nfa = AutNFAIT::<usize>::from_raw(alphabet,
+                                           hashset!{0}, // initials
+                                           hashset!{0}, // finals
+                                           transitions,
+                                           epsilon_trans).unwrap();
     (printer,nfa)
 }
 
 
-#[test]
-fn tests() {
+pub fn get_two_cycles_nfa() -> (TestNFAPrinter,AutNFAIT<usize>) {
+    let printer = TestNFAPrinter::get_printer();
+    let alphabet : HashSet<usize> = (0..printer.map.len()).collect();
+    let mut transitions = vec![hashmap!{};4];
+    transitions[0].insert(printer.map.iter().position(|x| x == "a").unwrap(), hashset!{1});
+    transitions[1].insert(printer.map.iter().position(|x| x == "b").unwrap(), hashset!{0});
+    transitions[2].insert(printer.map.iter().position(|x| x == "a").unwrap(), hashset!{3});
+    transitions[3].insert(printer.map.iter().position(|x| x == "c").unwrap(), hashset!{2});
+    let nfa = AutNFA::<usize>::from_raw(alphabet,
+                                         hashset!{0,2}, // initials
+                                         hashset!{0,2}, // finals
+                                         transitions).unwrap();
+    (printer,nfa.to_nfait())
+}
 
-    let param_accept = NfaWordAnalysisParameterization::new(
-        NfaWordAnalysisResetOn::Initials,
-        NfaWordAnalysisPolicy::StopAtDeviation);
-    let param_accept_start_spec = NfaWordAnalysisParameterization::new(
-        NfaWordAnalysisResetOn::Specific(hashset!{1}),
-        NfaWordAnalysisPolicy::StopAtDeviation);
-    let param_reset_no_skip = NfaWordAnalysisParameterization::new(
-        NfaWordAnalysisResetOn::Initials,
-        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::AllStates,false));
-    let param_reset_then_skip = NfaWordAnalysisParameterization::new(
-        NfaWordAnalysisResetOn::Initials,
-        NfaWordAnalysisPolicy::TryResetThenMaySkip(NfaWordAnalysisResetOn::AllStates,true));
 
-    let (printer,nfa) = get_rv_nfa();
-    ana_test("accept".to_string(),
-             printer.clone(),
-             param_accept.clone(),
-             nfa.clone(),
-             vec![
-                 "a".to_string(),
-                 "b".to_string(),
-                 "c".to_string()
-             ]);
-    ana_test("fail".to_string(),
-             printer.clone(),
-             param_accept.clone(),
-             nfa.clone(),
-             vec![
-                 "b".to_string()
-             ]);
-    ana_test("start_away".to_string(),
-             printer.clone(),
-             param_accept_start_spec.clone(),
-             nfa.clone(),
-             vec![
-                 "b".to_string()
-             ]);
-    ana_test("reset".to_string(),
-             printer.clone(),
-             param_reset_no_skip.clone(),
-             nfa.clone(),
-             vec![
-                 "a".to_string(),
-                 "c".to_string()
-             ]);
-    ana_test("reset_fail".to_string(),
-             printer.clone(),
-             param_reset_no_skip.clone(),
-             nfa.clone(),
-             vec![
-                 "a".to_string(),
-                 "d".to_string()
-             ]);
-    ana_test("skip".to_string(),
-             printer,
-             param_reset_then_skip.clone(),
-             nfa,
-             vec![
-                 "a".to_string(),
-                 "d".to_string(),
-                 "b".to_string()
-             ]);
-}
\ No newline at end of file
+/// Returns an NFA whose language is the given set of words.
+pub fn get_words_nfa(printer : &TestNFAPrinter, words : Vec<Vec<&str>>) -> AutNFAIT<usize> {
+    let alphabet : HashSet<usize> = (0..printer.map.len()).collect();
+    let mut transitions = vec![hashmap!{}];
+    let mut finals = hashset!{};
+    for word in words {
+        let mut current = 0;
+        for x in word {
+            let letter = printer.map.iter().position(|y| y == x).unwrap();
+            let target = transitions.len();
+            transitions.push(hashmap!{});
+            transitions[current].entry(letter).or_insert_with(HashSet::new).insert(target);
+            current = target;
+        }
+        finals.insert(current);
+    }
+    let epsilon_trans = vec![hashset!{};transitions.len()];
+    AutNFAIT::<usize>::from_raw(alphabet, hashset!{0}, finals, transitions, epsilon_trans).unwrap()
+}
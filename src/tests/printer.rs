@@ -27,7 +27,7 @@ impl TestNFAPrinter {
     }
 
     pub fn get_printer() -> Self {
-        let map = vec!["a","b","c","d","e","f"].iter().map(|x| x.to_string()).collect();
+        let map = ["a","b","c","d","e","f"].iter().map(|x| x.to_string()).collect();
         Self::new(map)
     }
 }
@@ -40,9 +40,7 @@ const SYNTAX_EPSILON_CLEAR : &str = "𝜀";
 const SYNTAX_EPSILON_HTML : &str = "&#x3B5;";
 
 const SYNTAX_WILDCARD_DOT : &str = ".";
-const SYNTAX_WILDCARD_HASHTAG : &str = "#";
 
-const SYNTAX_CONCATENATION_EMPTY : &str = "";
 const SYNTAX_CONCATENATION_DOT : &str = ".";
 const SYNTAX_ALTERNATION : &str = "|";
 